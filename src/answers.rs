@@ -0,0 +1,172 @@
+//! Expected answers for the real inputs, so that a refactor cannot silently change them.
+//!
+//! The answers of a day are stored next to its input, in `input/2024/05-answers` for day 5 of 2024,
+//! with one line per part:
+//!
+//! ```text
+//! 1: 143
+//! 2: 123
+//! ```
+//!
+//! `aoc run` records the answers of parts that have none yet, and `aoc submit` forgets those that the
+//! website rejects. They can also be written by hand.
+
+use crate::input::{self, InputError};
+use crate::parse::{self, ParseError};
+use crate::Part;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// The recorded answers of one day.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+/// The result of comparing an answer to the recorded one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    /// The answer matches.
+    Pass,
+    /// The answer differs from the recorded one.
+    Fail { expected: String },
+    /// There is no recorded answer.
+    Missing,
+}
+
+impl Answers {
+    /// Parse the contents of an answers file.
+    ///
+    /// ```rust
+    /// # use aoc::answers::Answers;
+    /// # use aoc::Part;
+    /// let answers = Answers::parse("1: 143\n").unwrap();
+    /// assert_eq!(answers.get(Part::One), Some("143"));
+    /// assert_eq!(answers.get(Part::Two), None);
+    /// assert!(Answers::parse("3: 1").is_err());
+    /// ```
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let mut answers = Self::default();
+        for line in text.lines().filter(|line| !line.trim().is_empty()) {
+            let (part, answer) = parse::split_once(line, ":")?;
+            let part = part
+                .trim()
+                .parse()
+                .map_err(|e: String| ParseError::new(part, e))?;
+            answers.set(part, answer.trim());
+        }
+        Ok(answers)
+    }
+
+    /// The recorded answer of a part.
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+
+    /// Record the answer of a part, replacing the previous one.
+    pub fn set(&mut self, part: Part, answer: impl Into<String>) {
+        let answer = Some(answer.into());
+        match part {
+            Part::One => self.part1 = answer,
+            Part::Two => self.part2 = answer,
+        }
+    }
+
+    /// Forget the recorded answer of a part, returning it.
+    ///
+    /// ```rust
+    /// # use aoc::answers::Answers;
+    /// # use aoc::Part;
+    /// let mut answers = Answers::parse("1: 143\n2: 123\n").unwrap();
+    /// assert_eq!(answers.clear(Part::One).as_deref(), Some("143"));
+    /// assert_eq!(answers.to_string(), "2: 123\n");
+    /// ```
+    pub fn clear(&mut self, part: Part) -> Option<String> {
+        match part {
+            Part::One => self.part1.take(),
+            Part::Two => self.part2.take(),
+        }
+    }
+
+    /// Compare an answer to the recorded one.
+    ///
+    /// ```rust
+    /// # use aoc::answers::{Answers, Verdict};
+    /// # use aoc::Part;
+    /// let answers = Answers::parse("1: 143").unwrap();
+    /// assert_eq!(answers.check(Part::One, "143"), Verdict::Pass);
+    /// assert_eq!(answers.check(Part::One, "144"), Verdict::Fail { expected: "143".into() });
+    /// assert_eq!(answers.check(Part::Two, "123"), Verdict::Missing);
+    /// ```
+    pub fn check(&self, part: Part, answer: &str) -> Verdict {
+        match self.get(part) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Missing,
+        }
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for part in Part::BOTH {
+            if let Some(answer) = self.get(part) {
+                writeln!(f, "{part}: {answer}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The answers file of a day could not be loaded.
+#[derive(Debug)]
+pub enum AnswersError {
+    Input(InputError),
+    Parse(ParseError),
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Input(e) => write!(f, "{e}"),
+            Self::Parse(e) => write!(f, "invalid answers: {e}"),
+        }
+    }
+}
+
+impl Error for AnswersError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Input(e) => Some(e),
+            Self::Parse(e) => Some(e),
+        }
+    }
+}
+
+/// The answers file of a day.
+pub fn path(year: u16, day: u8) -> PathBuf {
+    input::year_dir(year).join(format!("{day:02}-answers"))
+}
+
+/// Load the recorded answers of a day. A missing file has no answers.
+pub fn load(year: u16, day: u8) -> Result<Answers, AnswersError> {
+    match input::path(path(year, day)) {
+        Ok(input) => input.solve(Answers::parse).map_err(AnswersError::Parse),
+        Err(InputError::Missing(_)) => Ok(Answers::default()),
+        Err(e) => Err(AnswersError::Input(e)),
+    }
+}
+
+/// Write the answers of a day.
+pub fn save(year: u16, day: u8, answers: &Answers) -> io::Result<()> {
+    fs::write(path(year, day), answers.to_string())
+}
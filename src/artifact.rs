@@ -0,0 +1,121 @@
+//! Diagnostic output of solutions, like a picture of the robots in day 14 or a graph of the circuit in day 24.
+//!
+//! Solutions hand such output to [`emit`] instead of printing it. By default it is printed to stdout,
+//! but the runner can capture it to print it together with the answers, save it to files
+//! so that stdout only contains the answers, or drop it while benchmarking.
+//!
+//! The sink is set per thread, so [`emit`] must be called from the thread that runs the part,
+//! never from inside [`parallel::iter`](crate::parallel::iter). Runners that solve several days at once
+//! use [`scope`], which keeps the artifacts of each day apart.
+//!
+//! ```rust
+//! # use aoc::artifact::{self, Sink};
+//! let ((), outer) = artifact::scope(Sink::Capture, || {
+//!     artifact::emit("a.txt", "first day");
+//!     // A thread that waits for parallel work can pick up another day in the middle of this one.
+//!     let ((), inner) = artifact::scope(Sink::Capture, || artifact::emit("b.txt", "second day"));
+//!     assert_eq!(inner, "second day\n");
+//!     artifact::emit("a.txt", "first day again");
+//! });
+//! assert_eq!(outer, "first day\nfirst day again\n");
+//! ```
+
+use std::cell::RefCell;
+use std::fmt::Display;
+use std::fs;
+use std::path::PathBuf;
+
+/// Where artifacts go.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Sink {
+    /// Print artifacts to stdout.
+    Print,
+    /// Keep the text of artifacts until [`take_captured`] is called.
+    Capture,
+    /// Save every artifact to a file with its name in this directory.
+    Save(PathBuf),
+    /// Drop artifacts.
+    Discard,
+}
+
+thread_local! {
+    static SINK: RefCell<Sink> = const { RefCell::new(Sink::Print) };
+    static CAPTURED: RefCell<String> = const { RefCell::new(String::new()) };
+    static SAVED: RefCell<Vec<PathBuf>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Send the artifacts of the current thread to this sink from now on.
+pub fn set_sink(sink: Sink) {
+    SINK.with(|current| *current.borrow_mut() = sink);
+}
+
+/// Run `f` with the artifacts of the current thread going to `sink`, and return its result together
+/// with the text that was captured meanwhile.
+///
+/// Afterwards the previous sink is back, together with what was captured or saved before, even if `f` panics.
+/// So scopes can nest, which is what happens when a rayon thread that waits for parallel work
+/// picks up another job of the same pool.
+pub fn scope<T>(sink: Sink, f: impl FnOnce() -> T) -> (T, String) {
+    let _outer = Outer {
+        sink: SINK.with(|current| current.replace(sink)),
+        captured: take_captured(),
+        saved: take_saved(),
+    };
+    let result = f();
+    (result, take_captured())
+}
+
+/// The state of an enclosing [`scope`], which is restored when the inner scope ends.
+struct Outer {
+    sink: Sink,
+    captured: String,
+    saved: Vec<PathBuf>,
+}
+
+impl Drop for Outer {
+    fn drop(&mut self) {
+        SINK.with(|sink| *sink.borrow_mut() = std::mem::replace(&mut self.sink, Sink::Discard));
+        CAPTURED.with(|captured| *captured.borrow_mut() = std::mem::take(&mut self.captured));
+        SAVED.with(|saved| *saved.borrow_mut() = std::mem::take(&mut self.saved));
+    }
+}
+
+/// Hand an artifact to the sink. The name is used as the file name when saving, like `"circuit.dot"`.
+///
+/// Artifacts are diagnostics, so an artifact that cannot be saved is reported on stderr instead of failing.
+pub fn emit(name: &str, contents: impl Display) {
+    let sink = SINK.with(|sink| sink.borrow().clone());
+    match sink {
+        Sink::Print => print!("{}", with_newline(contents)),
+        Sink::Capture => {
+            CAPTURED.with(|captured| captured.borrow_mut().push_str(&with_newline(contents)))
+        }
+        Sink::Discard => {}
+        Sink::Save(dir) => {
+            let path = dir.join(name);
+            match fs::create_dir_all(&dir).and_then(|_| fs::write(&path, contents.to_string())) {
+                Ok(()) => SAVED.with(|saved| saved.borrow_mut().push(path)),
+                Err(e) => eprintln!("could not save artifact '{}': {e}", path.display()),
+            }
+        }
+    }
+}
+
+/// Return the text of the artifacts that were captured by the current thread since the last call.
+pub fn take_captured() -> String {
+    CAPTURED.with(|captured| captured.take())
+}
+
+/// Return the paths of the artifacts that were saved by the current thread since the last call.
+pub fn take_saved() -> Vec<PathBuf> {
+    SAVED.with(|saved| saved.take())
+}
+
+/// The text of an artifact, ending with a newline so that the following output starts on its own line.
+fn with_newline(contents: impl Display) -> String {
+    let mut text = contents.to_string();
+    if !text.ends_with('\n') {
+        text.push('\n');
+    }
+    text
+}
@@ -0,0 +1,302 @@
+//! Measure how long parsing and each part of a day take.
+//!
+//! Every day is solved a few times to warm up caches, then repeatedly while the times are recorded.
+//! The results can be written to a JSON file to compare them between runs.
+//!
+//! The medians can also be saved as a [`Baseline`] that is checked in,
+//! so that later runs can detect when a day became slower.
+
+use crate::input::Input;
+use crate::parse::ParseError;
+use crate::Day;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+use std::time::{Duration, Instant};
+
+/// How often to solve each day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    /// Number of runs before measuring.
+    pub warmup: usize,
+    /// Number of measured runs.
+    pub runs: usize,
+    /// Stop measuring a day after this time, even if not all runs are done. At least one run is always measured.
+    pub max_time: Duration,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            warmup: 1,
+            runs: 10,
+            max_time: Duration::from_secs(5),
+        }
+    }
+}
+
+/// Summary of several measurements of the same phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub median_ns: u64,
+    pub min_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Stats {
+    /// Summarize measurements. Returns `None` if there are none.
+    ///
+    /// ```rust
+    /// # use aoc::bench::Stats;
+    /// # use std::time::Duration;
+    /// let samples = [3, 1, 2, 10].map(Duration::from_nanos);
+    /// let stats = Stats::new(&samples).unwrap();
+    /// assert_eq!((stats.median_ns, stats.min_ns, stats.max_ns), (2, 1, 10));
+    /// ```
+    pub fn new(samples: &[Duration]) -> Option<Self> {
+        let mut nanos: Vec<u64> = samples.iter().map(|d| d.as_nanos() as u64).collect();
+        nanos.sort_unstable();
+        // For an even number of samples, take the lower of the two middle values.
+        let median = *nanos.get((nanos.len().checked_sub(1)?) / 2)?;
+        Some(Self {
+            median_ns: median,
+            min_ns: nanos[0],
+            max_ns: nanos[nanos.len() - 1],
+        })
+    }
+
+    pub fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_ns)
+    }
+
+    pub fn min(&self) -> Duration {
+        Duration::from_nanos(self.min_ns)
+    }
+
+    pub fn max(&self) -> Duration {
+        Duration::from_nanos(self.max_ns)
+    }
+}
+
+/// The measurements of one day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayStats {
+    pub year: u16,
+    pub day: u8,
+    /// How many runs were measured.
+    pub runs: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl DayStats {
+    /// The phases of the day with their names.
+    pub fn phases(&self) -> [(&'static str, Stats); 3] {
+        [
+            ("parse", self.parse),
+            ("part1", self.part1),
+            ("part2", self.part2),
+        ]
+    }
+}
+
+/// The results of a whole benchmark run, as written to the results file.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Report {
+    pub days: Vec<DayStats>,
+}
+
+/// The median time of every phase, per day. Stored in a file to detect regressions.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    pub days: Vec<BaselineDay>,
+}
+
+/// The median time of every phase of one day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BaselineDay {
+    pub year: u16,
+    pub day: u8,
+    pub parse_ns: u64,
+    pub part1_ns: u64,
+    pub part2_ns: u64,
+}
+
+impl BaselineDay {
+    fn phases(&self) -> [(&'static str, u64); 3] {
+        [
+            ("parse", self.parse_ns),
+            ("part1", self.part1_ns),
+            ("part2", self.part2_ns),
+        ]
+    }
+}
+
+impl From<&DayStats> for BaselineDay {
+    fn from(stats: &DayStats) -> Self {
+        Self {
+            year: stats.year,
+            day: stats.day,
+            parse_ns: stats.parse.median_ns,
+            part1_ns: stats.part1.median_ns,
+            part2_ns: stats.part2.median_ns,
+        }
+    }
+}
+
+/// Phases that take less time than this in both runs are never reported as slower,
+/// because timer noise dominates such short measurements.
+pub const NOISE_FLOOR: Duration = Duration::from_micros(50);
+
+/// How a phase compares to the baseline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Comparison {
+    pub year: u16,
+    pub day: u8,
+    pub phase: &'static str,
+    pub baseline: Duration,
+    pub current: Duration,
+    /// Whether the phase became slower by more than the allowed percentage.
+    pub regressed: bool,
+}
+
+impl Comparison {
+    /// How much slower the phase became, in percent. Negative if it became faster.
+    pub fn change_percent(&self) -> f64 {
+        let baseline = self.baseline.as_secs_f64();
+        let current = self.current.as_secs_f64();
+        if baseline == 0.0 {
+            0.0
+        } else {
+            (current - baseline) / baseline * 100.0
+        }
+    }
+}
+
+impl Baseline {
+    /// Replace the medians of all days in the report. Other days are kept.
+    pub fn update(&mut self, report: &Report) {
+        for stats in &report.days {
+            self.days
+                .retain(|day| (day.year, day.day) != (stats.year, stats.day));
+            self.days.push(stats.into());
+        }
+        self.days.sort_by_key(|day| (day.year, day.day));
+    }
+
+    /// The baseline of a day, if there is one.
+    pub fn get(&self, year: u16, day: u8) -> Option<&BaselineDay> {
+        self.days
+            .iter()
+            .find(|baseline| (baseline.year, baseline.day) == (year, day))
+    }
+
+    /// Compare every phase of the report that has a baseline.
+    /// A phase regressed if its median is more than `threshold_percent` slower than the baseline.
+    ///
+    /// ```rust
+    /// # use aoc::bench::{Baseline, BaselineDay, DayStats, Report, Stats};
+    /// let stats = |ms: u64| Stats { median_ns: ms * 1_000_000, min_ns: 0, max_ns: 0 };
+    /// let baseline = Baseline {
+    ///     days: vec![BaselineDay { year: 2024, day: 1, parse_ns: 1_000_000, part1_ns: 1_000_000, part2_ns: 1_000_000 }],
+    /// };
+    /// let report = Report {
+    ///     days: vec![DayStats { year: 2024, day: 1, runs: 1, parse: stats(1), part1: stats(2), part2: stats(1) }],
+    /// };
+    /// let regressed: Vec<_> = baseline.compare(&report, 20.0).into_iter().filter(|c| c.regressed).collect();
+    /// assert_eq!(regressed.len(), 1);
+    /// assert_eq!(regressed[0].phase, "part1");
+    /// assert_eq!(regressed[0].change_percent(), 100.0);
+    /// ```
+    pub fn compare(&self, report: &Report, threshold_percent: f64) -> Vec<Comparison> {
+        let mut comparisons = Vec::new();
+        for stats in &report.days {
+            let Some(baseline) = self.get(stats.year, stats.day) else {
+                continue;
+            };
+            let current = BaselineDay::from(stats);
+            for ((phase, baseline), (_, current)) in
+                baseline.phases().into_iter().zip(current.phases())
+            {
+                let baseline = Duration::from_nanos(baseline);
+                let current = Duration::from_nanos(current);
+                let mut comparison = Comparison {
+                    year: stats.year,
+                    day: stats.day,
+                    phase,
+                    baseline,
+                    current,
+                    regressed: false,
+                };
+                comparison.regressed = current.max(baseline) >= NOISE_FLOOR
+                    && comparison.change_percent() > threshold_percent;
+                comparisons.push(comparison);
+            }
+        }
+        comparisons
+    }
+}
+
+/// Solve a day repeatedly and measure each phase.
+pub fn bench(day: &Day, input: &Input, config: &Config) -> Result<DayStats, ParseError> {
+    for _ in 0..config.warmup {
+        day.time(input)?;
+    }
+
+    let mut parse = Vec::new();
+    let mut part1 = Vec::new();
+    let mut part2 = Vec::new();
+    let start = Instant::now();
+    while parse.len() < config.runs.max(1)
+        && (parse.is_empty() || start.elapsed() < config.max_time)
+    {
+        let timings = day.time(input)?;
+        parse.push(timings.parse);
+        part1.push(timings.part1);
+        part2.push(timings.part2);
+    }
+
+    Ok(DayStats {
+        year: day.year(),
+        day: day.number(),
+        runs: parse.len(),
+        parse: Stats::new(&parse).unwrap(),
+        part1: Stats::new(&part1).unwrap(),
+        part2: Stats::new(&part2).unwrap(),
+    })
+}
+
+/// Formats a duration with 3 significant digits and a fitting unit.
+///
+/// ```rust
+/// # use aoc::bench::Human;
+/// # use std::time::Duration;
+/// assert_eq!(Human(Duration::from_nanos(1_234)).to_string(), "1.23µs");
+/// assert_eq!(Human(Duration::from_millis(56)).to_string(), "56.0ms");
+/// assert_eq!(Human(Duration::from_secs(2)).to_string(), "2.00s");
+/// ```
+pub struct Human(pub Duration);
+
+impl Display for Human {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nanos = self.0.as_nanos() as f64;
+        let (value, unit) = if nanos < 1e3 {
+            (nanos, "ns")
+        } else if nanos < 1e6 {
+            (nanos / 1e3, "µs")
+        } else if nanos < 1e9 {
+            (nanos / 1e6, "ms")
+        } else {
+            (nanos / 1e9, "s")
+        };
+        let decimals = if value < 10.0 {
+            2
+        } else if value < 100.0 {
+            1
+        } else {
+            0
+        };
+        let text = format!("{value:.decimals$}{unit}");
+        f.pad(&text)
+    }
+}
@@ -0,0 +1,78 @@
+//! A tiny command line parser, just enough for the subcommands of the runner.
+
+use anyhow::{bail, Context};
+use std::str::FromStr;
+
+/// The remaining command line arguments. Options are taken out by name,
+/// everything that is left over at the end is an error.
+pub struct Args {
+    args: Vec<String>,
+}
+
+impl Args {
+    pub fn new(args: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            args: args.into_iter().collect(),
+        }
+    }
+
+    /// Take the next argument that is not an option.
+    ///
+    /// Take all options first, otherwise the value of an option might be mistaken for a positional argument.
+    pub fn positional(&mut self) -> Option<String> {
+        let i = self.args.iter().position(|arg| !arg.starts_with("--"))?;
+        Some(self.args.remove(i))
+    }
+
+    /// Take a flag like `--example`. Returns true if it was given.
+    pub fn flag(&mut self, name: &str) -> bool {
+        let flag = format!("--{name}");
+        let given = self.args.contains(&flag);
+        self.args.retain(|arg| arg != &flag);
+        given
+    }
+
+    /// Take an option with a value, given as `--name value` or `--name=value`.
+    pub fn option(&mut self, name: &str) -> anyhow::Result<Option<String>> {
+        let flag = format!("--{name}");
+        let prefix = format!("--{name}=");
+        let mut value = None;
+        while let Some(i) = self
+            .args
+            .iter()
+            .position(|arg| arg == &flag || arg.starts_with(&prefix))
+        {
+            let arg = self.args.remove(i);
+            value = match arg.strip_prefix(&prefix) {
+                Some(v) => Some(v.to_string()),
+                None if i < self.args.len() => Some(self.args.remove(i)),
+                None => bail!("{flag} needs a value"),
+            };
+        }
+        Ok(value)
+    }
+
+    /// Take an option and parse its value.
+    pub fn parsed<T>(&mut self, name: &str) -> anyhow::Result<Option<T>>
+    where
+        T: FromStr,
+        T::Err: std::fmt::Display,
+    {
+        self.option(name)?
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|e| anyhow::anyhow!("{e}"))
+                    .with_context(|| format!("invalid value for --{name}: '{value}'"))
+            })
+            .transpose()
+    }
+
+    /// Fail if there are arguments that nobody asked for.
+    pub fn finish(self) -> anyhow::Result<()> {
+        match self.args.first() {
+            Some(arg) => bail!("unexpected argument '{arg}'"),
+            None => Ok(()),
+        }
+    }
+}
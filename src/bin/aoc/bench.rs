@@ -0,0 +1,158 @@
+//! The `bench` command.
+
+use crate::args::Args;
+use crate::config;
+use crate::run::{self, Source};
+use anyhow::{bail, Context};
+use aoc::artifact::{self, Sink};
+use aoc::bench::{self, Baseline, Config, Human, Report};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Where the results are written if no other file is given.
+const DEFAULT_OUTPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/target/bench.json");
+
+/// The baseline of this machine. Timings of other machines are not comparable, so it is not checked in.
+const DEFAULT_BASELINE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/target/bench-baseline.json");
+
+/// Allowed slowdown compared to the baseline, in percent.
+const DEFAULT_THRESHOLD: f64 = 20.0;
+
+/// What to do with the baseline after measuring.
+enum BaselineMode {
+    Save,
+    Compare,
+}
+
+pub fn bench(mut args: Args) -> anyhow::Result<()> {
+    let default = Config::default();
+    let config = Config {
+        warmup: args.parsed("warmup")?.unwrap_or(default.warmup),
+        runs: args.parsed("runs")?.unwrap_or(default.runs),
+        max_time: args
+            .parsed("max-time")?
+            .map(Duration::from_secs_f64)
+            .unwrap_or(default.max_time),
+    };
+    let output = PathBuf::from(
+        args.option("output")?
+            .unwrap_or_else(|| DEFAULT_OUTPUT.to_string()),
+    );
+    let baseline_mode = match args.option("baseline")?.as_deref() {
+        None => None,
+        Some("save") => Some(BaselineMode::Save),
+        Some("compare") => Some(BaselineMode::Compare),
+        Some(other) => bail!("--baseline must be save or compare, got '{other}'"),
+    };
+    let baseline_file = PathBuf::from(
+        args.option("baseline-file")?
+            .unwrap_or_else(|| DEFAULT_BASELINE.to_string()),
+    );
+    let threshold = args.parsed("threshold")?.unwrap_or(DEFAULT_THRESHOLD);
+    let year = config::year(&mut args)?;
+    let days = match args.positional() {
+        Some(days) => run::parse_days(year, &days)?,
+        None => run::parse_days(year, "all")?,
+    };
+    args.finish()?;
+    if config.runs == 0 {
+        bail!("--runs must be at least 1");
+    }
+
+    println!(
+        "{:<4} {:<6} {:>9} {:>9} {:>9}",
+        "Day", "Phase", "Median", "Min", "Max"
+    );
+    // Artifacts would be printed on every run.
+    artifact::set_sink(Sink::Discard);
+    let mut report = Report::default();
+    for day in days {
+        let input = run::load(day, Source::Real)?;
+        let stats = bench::bench(day, &input, &config)?;
+        for (phase, phase_stats) in stats.phases() {
+            println!(
+                "{:<4} {phase:<6} {:>9} {:>9} {:>9}",
+                format!("{:02}", stats.day),
+                Human(phase_stats.median()),
+                Human(phase_stats.min()),
+                Human(phase_stats.max()),
+            );
+        }
+        report.days.push(stats);
+    }
+
+    if let Some(dir) = output.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let json = serde_json::to_string_pretty(&report)?;
+    fs::write(&output, json + "\n")
+        .with_context(|| format!("could not write results to '{}'", output.display()))?;
+    println!("Results written to {}", output.display());
+
+    match baseline_mode {
+        None => Ok(()),
+        Some(BaselineMode::Save) => save_baseline(&report, &baseline_file),
+        Some(BaselineMode::Compare) => compare_baseline(&report, &baseline_file, threshold),
+    }
+}
+
+/// Update the baseline with the medians of the measured days.
+fn save_baseline(report: &Report, file: &Path) -> anyhow::Result<()> {
+    let mut baseline = if file.exists() {
+        read_baseline(file)?
+    } else {
+        Baseline::default()
+    };
+    baseline.update(report);
+    let json = serde_json::to_string_pretty(&baseline)?;
+    fs::write(file, json + "\n")
+        .with_context(|| format!("could not write baseline to '{}'", file.display()))?;
+    println!("Baseline saved to {}", file.display());
+    Ok(())
+}
+
+/// Print how every phase compares to the baseline and fail if any became too slow.
+fn compare_baseline(report: &Report, file: &Path, threshold: f64) -> anyhow::Result<()> {
+    let baseline = read_baseline(file)?;
+    let comparisons = baseline.compare(report, threshold);
+
+    println!();
+    println!(
+        "{:<4} {:<6} {:>9} {:>9} {:>8}",
+        "Day", "Phase", "Baseline", "Current", "Change"
+    );
+    for c in &comparisons {
+        let marker = if c.regressed { "  SLOWER" } else { "" };
+        println!(
+            "{:<4} {:<6} {:>9} {:>9} {:>+7.1}%{marker}",
+            format!("{:02}", c.day),
+            c.phase,
+            Human(c.baseline),
+            Human(c.current),
+            c.change_percent(),
+        );
+    }
+    for stats in &report.days {
+        if baseline.get(stats.year, stats.day).is_none() {
+            println!("{:02}   no baseline", stats.day);
+        }
+    }
+
+    let regressed = comparisons.iter().filter(|c| c.regressed).count();
+    if regressed > 0 {
+        bail!("{regressed} phases are more than {threshold}% slower than the baseline");
+    }
+    println!("No phase is more than {threshold}% slower than the baseline");
+    Ok(())
+}
+
+fn read_baseline(file: &Path) -> anyhow::Result<Baseline> {
+    let json = fs::read_to_string(file).with_context(|| {
+        format!(
+            "could not read baseline '{}', save one with --baseline save",
+            file.display()
+        )
+    })?;
+    serde_json::from_str(&json).with_context(|| format!("invalid baseline '{}'", file.display()))
+}
@@ -0,0 +1,94 @@
+//! Talking to the Advent of Code website.
+//!
+//! Requests are authenticated with the session cookie in `AOC_SESSION`, which is read from the environment
+//! or from the `.env` file next to `Cargo.toml`. `AOC_URL` replaces the website, for example with a local stub server.
+
+use crate::config;
+use anyhow::{bail, Context};
+
+/// Environment variable with the session cookie.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Environment variable that overrides the base URL of the website.
+pub const URL_VAR: &str = "AOC_URL";
+
+const DEFAULT_URL: &str = "https://adventofcode.com";
+
+/// The website asks automated tools to identify themselves.
+const USER_AGENT: &str = concat!("aoc-runner/", env!("CARGO_PKG_VERSION"));
+
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    /// Create a client with the session and URL from the environment or `.env`.
+    pub fn from_env() -> anyhow::Result<Self> {
+        let Some(session) = config::var(SESSION_VAR)? else {
+            bail!("{SESSION_VAR} is not set, add it to the environment or to .env");
+        };
+        let base_url = config::var(URL_VAR)?.unwrap_or_else(|| DEFAULT_URL.to_string());
+        Ok(Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        })
+    }
+
+    /// The URL of a page, like `/2024/day/5/input`.
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.base_url)
+    }
+
+    /// Get a page. Fails if the website answers with an error.
+    pub fn get(&self, path: &str) -> anyhow::Result<String> {
+        let request = self.agent.get(&self.url(path));
+        self.send(path, request.set("Cookie", &self.cookie()).call())
+    }
+
+    /// Post a form to a page. Fails if the website answers with an error.
+    pub fn post(&self, path: &str, form: &[(&str, &str)]) -> anyhow::Result<String> {
+        let request = self.agent.post(&self.url(path));
+        self.send(path, request.set("Cookie", &self.cookie()).send_form(form))
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    fn send(
+        &self,
+        path: &str,
+        response: Result<ureq::Response, ureq::Error>,
+    ) -> anyhow::Result<String> {
+        let url = self.url(path);
+        match response {
+            Ok(response) => response
+                .into_string()
+                .with_context(|| format!("could not read the response of {url}")),
+            Err(ureq::Error::Status(status, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                let hint = match status {
+                    400 | 500 => ", the session in AOC_SESSION is probably invalid or expired",
+                    404 => ", the puzzle is probably not unlocked yet",
+                    _ => "",
+                };
+                bail!("{url} answered with {status}{hint}: {}", summary(&body))
+            }
+            Err(e) => Err(e).with_context(|| format!("could not reach {url}")),
+        }
+    }
+}
+
+/// Whether a response is a HTML page instead of plain text.
+pub fn is_html(body: &str) -> bool {
+    let start = body.trim_start().to_ascii_lowercase();
+    start.starts_with("<!doctype") || start.starts_with("<html")
+}
+
+/// The first line of a response, for error messages.
+fn summary(body: &str) -> &str {
+    body.lines().next().unwrap_or_default().trim()
+}
@@ -0,0 +1,46 @@
+//! Settings that are read from the environment, or from the `.env` file next to `Cargo.toml`.
+
+use crate::args::Args;
+use anyhow::Context;
+use aoc::days;
+use std::path::Path;
+use std::{env, fs, io};
+
+/// Environment variable with the default year of all commands.
+pub const YEAR_VAR: &str = "AOC_YEAR";
+
+/// The year given with `--year`. Defaults to `AOC_YEAR`, or else to the latest year with solutions.
+pub fn year(args: &mut Args) -> anyhow::Result<u16> {
+    if let Some(year) = args.parsed("year")? {
+        return Ok(year);
+    }
+    if let Some(year) = var(YEAR_VAR)? {
+        return year
+            .parse()
+            .with_context(|| format!("invalid year '{year}' in {YEAR_VAR}"));
+    }
+    days::years()
+        .last()
+        .context("there are no solutions yet, pass --year")
+}
+
+/// Read a variable from the environment, or from `.env` if it is not set.
+pub fn var(name: &str) -> anyhow::Result<Option<String>> {
+    if let Ok(value) = env::var(name) {
+        return Ok(Some(value));
+    }
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(".env");
+    let dotenv = match fs::read_to_string(&path) {
+        Ok(dotenv) => dotenv,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e).with_context(|| format!("could not read '{}'", path.display())),
+    };
+    let value = dotenv
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.starts_with('#'))
+        .filter_map(|line| line.split_once('='))
+        .find(|(key, _)| key.trim().trim_start_matches("export ") == name)
+        .map(|(_, value)| value.trim().trim_matches(['"', '\'']).to_string());
+    Ok(value)
+}
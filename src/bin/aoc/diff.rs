@@ -0,0 +1,69 @@
+//! The `diff` command.
+
+use crate::args::Args;
+use crate::config;
+use crate::panics::catch;
+use anyhow::{bail, Context};
+use aoc::artifact::{self, Sink};
+use aoc::input::Input;
+use aoc::{days, Part};
+
+/// The size of generated inputs if `--size` is not given. References are slow, so this is smaller than for `gen`.
+const DEFAULT_SIZE: usize = 20;
+
+pub fn diff(mut args: Args) -> anyhow::Result<()> {
+    let year = config::year(&mut args)?;
+    let first_seed = args.parsed("seed")?.unwrap_or(0);
+    let count: u64 = args.parsed("count")?.unwrap_or(100);
+    let size = args.parsed("size")?.unwrap_or(DEFAULT_SIZE);
+    let Some(day) = args.positional() else {
+        bail!("missing day to compare, see --help");
+    };
+    args.finish()?;
+    let number: u8 = day
+        .parse()
+        .with_context(|| format!("invalid day '{day}'"))?;
+    let day =
+        days::get(year, number).with_context(|| format!("day {number} of {year} is not solved"))?;
+
+    // Artifacts of hundreds of inputs are not interesting.
+    artifact::set_sink(Sink::Discard);
+    for seed in first_seed..first_seed + count {
+        let Some(text) = day.generate(seed, size) else {
+            bail!("day {number} of {year} has no input generator");
+        };
+        let input = Input::new(format!("<seed {seed}, size {size}>"), text);
+        let expected = day.reference(&input, &Part::BOTH)?;
+        let parts: Vec<(Part, String)> = Part::BOTH
+            .into_iter()
+            .zip(expected)
+            .filter_map(|(part, expected)| Some((part, expected?)))
+            .collect();
+        if parts.is_empty() {
+            bail!("day {number} of {year} has no reference solution");
+        }
+
+        let only_parts: Vec<Part> = parts.iter().map(|&(part, _)| part).collect();
+        let actual = match catch(|| day.solve(&input, &only_parts)) {
+            Ok(answers) => answers?.into_iter().map(Ok).collect(),
+            Err(panic) => vec![Err(panic); parts.len()],
+        };
+        for ((part, expected), actual) in parts.into_iter().zip(actual) {
+            let actual = match actual {
+                Ok(answer) if answer == expected => continue,
+                Ok(answer) => answer,
+                Err(panic) => format!("panicked: {panic}"),
+            };
+            println!("Part {part} differs for seed {seed}:");
+            println!("    solution:  {actual}");
+            println!("    reference: {expected}");
+            println!(
+                "See the input with: aoc gen {number} --year {year} --seed {seed} --size {size}"
+            );
+            bail!("the solution and the reference disagree");
+        }
+    }
+
+    println!("The solution and the reference agree on {count} inputs");
+    Ok(())
+}
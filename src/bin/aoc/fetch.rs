@@ -0,0 +1,44 @@
+//! The `fetch` command.
+
+use crate::args::Args;
+use crate::client::{self, Client};
+use crate::config;
+use anyhow::{bail, Context};
+use aoc::input;
+use std::fs;
+
+pub fn fetch(mut args: Args) -> anyhow::Result<()> {
+    let year = config::year(&mut args)?;
+    let Some(day) = args.positional() else {
+        bail!("missing day to fetch, see --help");
+    };
+    let day: u8 = day
+        .parse()
+        .with_context(|| format!("invalid day '{day}'"))?;
+    args.finish()?;
+    if !(1..=25).contains(&day) {
+        bail!("day must be between 1 and 25, got {day}");
+    }
+
+    // `aoc new` creates empty inputs, so only a file with content counts as downloaded.
+    let path = input::year_dir(year).join(format!("{day:02}"));
+    if fs::metadata(&path).is_ok_and(|metadata| metadata.len() > 0) {
+        println!("{} already exists", path.display());
+        return Ok(());
+    }
+
+    let client = Client::from_env()?;
+    let text = client.get(&format!("/{year}/day/{day}/input"))?;
+    if text.trim().is_empty() || client::is_html(&text) {
+        bail!(
+            "{} did not return a puzzle input, is the session in {} still valid?",
+            client.url(&format!("/{year}/day/{day}/input")),
+            client::SESSION_VAR
+        );
+    }
+
+    fs::create_dir_all(input::year_dir(year))?;
+    fs::write(&path, text).with_context(|| format!("could not write '{}'", path.display()))?;
+    println!("Downloaded {}", path.display());
+    Ok(())
+}
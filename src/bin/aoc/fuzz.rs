@@ -0,0 +1,100 @@
+//! The `fuzz` command.
+
+use crate::args::Args;
+use crate::config;
+use crate::panics::message;
+use anyhow::{bail, Context};
+use aoc::input::{self, Input};
+use aoc::random::{self, Rng};
+use aoc::{days, Day};
+use std::path::PathBuf;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+use std::{fs, iter};
+
+/// Where the input that broke the parser is saved, in a directory per year.
+const FAILURES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/target/fuzz");
+
+/// The size of the generated inputs that are mutated if `--size` is not given.
+const DEFAULT_SIZE: usize = 10;
+
+pub fn fuzz(mut args: Args) -> anyhow::Result<()> {
+    let year = config::year(&mut args)?;
+    let first_seed = args.parsed("seed")?.unwrap_or(0);
+    let count: u64 = args.parsed("count")?.unwrap_or(1000);
+    let size = args.parsed("size")?.unwrap_or(DEFAULT_SIZE);
+    let timeout = Duration::from_secs(args.parsed("timeout")?.unwrap_or(5));
+    let Some(day) = args.positional() else {
+        bail!("missing day to fuzz, see --help");
+    };
+    args.finish()?;
+    let number: u8 = day
+        .parse()
+        .with_context(|| format!("invalid day '{day}'"))?;
+    let day =
+        days::get(year, number).with_context(|| format!("day {number} of {year} is not solved"))?;
+
+    let corpus = corpus(day, size);
+    if corpus.is_empty() {
+        bail!("day {number} of {year} has neither examples nor an input generator");
+    }
+
+    let mut rejected = 0;
+    for seed in first_seed..first_seed + count {
+        let mut rng = Rng::new(seed);
+        let valid = rng.choose(&corpus);
+        let text = random::mutate(&mut rng, valid);
+        let input = Input::new(format!("<mutation {seed}>"), text.clone());
+
+        // Parse on another thread, which is left behind if it does not finish in time.
+        let (sender, receiver) = mpsc::channel();
+        let parser = thread::spawn(move || {
+            let _ = sender.send(day.run(&input, &[]).is_ok());
+        });
+        let failure = match receiver.recv_timeout(timeout) {
+            Ok(accepted) => {
+                rejected += u64::from(!accepted);
+                continue;
+            }
+            Err(RecvTimeoutError::Timeout) => format!("did not finish in {timeout:?}"),
+            Err(RecvTimeoutError::Disconnected) => match parser.join() {
+                Err(payload) => format!("panicked: {}", message(&*payload)),
+                Ok(()) => unreachable!("the parser sends a result unless it panics"),
+            },
+        };
+
+        let path = save(year, number, seed, &text)?;
+        println!("Parsing mutation {seed} {failure}");
+        println!("The input is saved in {}", path.display());
+        println!(
+            "Run it again with: aoc run {number} --year {year} --input {}",
+            path.display()
+        );
+        bail!("the parser of day {number} of {year} failed on malformed input");
+    }
+
+    println!("Parsed {count} mutated inputs, {rejected} of them were rejected");
+    Ok(())
+}
+
+/// The valid inputs to mutate: the examples that exist and one generated input.
+fn corpus(day: &Day, size: usize) -> Vec<String> {
+    let mut files: Vec<&str> = day.examples().iter().map(|example| example.file).collect();
+    files.dedup();
+    let examples = files
+        .into_iter()
+        .filter_map(|file| input::file(day.year(), file).ok())
+        .map(|input| input.text().to_string());
+    examples
+        .chain(iter::once(day.generate(0, size)).flatten())
+        .collect()
+}
+
+fn save(year: u16, number: u8, seed: u64, text: &str) -> anyhow::Result<PathBuf> {
+    let dir = PathBuf::from(FAILURES_DIR).join(year.to_string());
+    fs::create_dir_all(&dir).with_context(|| format!("cannot create {}", dir.display()))?;
+    let path = dir.join(format!("{number:02}-{seed}"));
+    fs::write(&path, text).with_context(|| format!("cannot write {}", path.display()))?;
+    Ok(path)
+}
@@ -0,0 +1,40 @@
+//! The `gen` command.
+
+use crate::args::Args;
+use crate::config;
+use anyhow::{bail, Context};
+use aoc::days;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The size of generated inputs if `--size` is not given.
+const DEFAULT_SIZE: usize = 100;
+
+pub fn generate(mut args: Args) -> anyhow::Result<()> {
+    let year = config::year(&mut args)?;
+    let seed = args.parsed("seed")?;
+    let size = args.parsed("size")?.unwrap_or(DEFAULT_SIZE);
+    let Some(day) = args.positional() else {
+        bail!("missing day to generate an input for, see --help");
+    };
+    args.finish()?;
+    let number: u8 = day
+        .parse()
+        .with_context(|| format!("invalid day '{day}'"))?;
+    let day =
+        days::get(year, number).with_context(|| format!("day {number} of {year} is not solved"))?;
+
+    let seed = match seed {
+        Some(seed) => seed,
+        None => {
+            let seed = SystemTime::now().duration_since(UNIX_EPOCH)?.as_nanos() as u64;
+            // The input goes to stdout, so the seed to reproduce it goes to stderr.
+            eprintln!("seed {seed}");
+            seed
+        }
+    };
+    let Some(input) = day.generate(seed, size) else {
+        bail!("day {number} of {year} has no input generator");
+    };
+    print!("{input}");
+    Ok(())
+}
@@ -0,0 +1,104 @@
+//! Runs the solutions of any day.
+
+mod args;
+mod bench;
+mod client;
+mod config;
+mod diff;
+mod fetch;
+mod fuzz;
+mod gen;
+mod new;
+mod panics;
+mod run;
+mod submit;
+mod verify;
+
+use anyhow::bail;
+use args::Args;
+use std::env;
+
+const USAGE: &str = "\
+Usage: aoc <command> [options]
+
+Commands:
+    run <days>           Solve the given days
+    bench [days]         Measure how long parsing and each part take, all days by default
+    diff <day>           Compare the solution of a day to its slow reference solution on generated inputs
+    fetch <day>          Download the input of a day to input/YYYY/NN, unless it was downloaded before
+    fuzz <day>           Parse randomly broken inputs of a day and stop at the first that panics or hangs
+    gen <day>            Print a random input of a day for stress testing
+    new <day>            Create the solution of a day from template.rs, register it and create its empty inputs
+    submit <day> <part>  Solve a part and submit the answer, wrong guesses are logged in input/YYYY/NN-guesses
+    verify [days]        Compare the answers to the recorded ones in input/YYYY/NN-answers, all days by default
+
+Days are given as a single day (5), an inclusive range (1..=10), a range without the end (1..10),
+a comma separated list (1,3,5) or all.
+
+Options for every command:
+    --year <year>     The year of the puzzles. Defaults to AOC_YEAR from the environment or .env,
+                      or else to the latest year with solutions
+
+Options for run:
+    --part <1|2>      Solve only one part
+    --example         Use the example input instead of the real one
+    --input <path>    Read the input from a file, or from stdin with '-'
+    --jobs <n>        Solve this many days in parallel, they are still printed in order. Default 1.
+                      Needs the parallel feature
+    --format <format> text, or json for one JSON object per line and day with the answers, their types,
+                      timings and the files of artifacts, which are saved to target/artifacts instead of printed
+
+Answers for the real input are recorded in input/YYYY/NN-answers the first time a part is solved.
+
+Options for bench:
+    --warmup <n>        Unmeasured runs before measuring, default 1
+    --runs <n>          Measured runs, default 10
+    --max-time <secs>   Stop measuring a day after this time, default 5
+    --output <path>     Write the results as JSON to this file, default target/bench.json
+    --baseline <mode>   save: store the medians in the baseline file,
+                        compare: fail if a phase is slower than in the baseline file
+    --baseline-file <path>  default target/bench-baseline.json
+    --threshold <percent>   Allowed slowdown for compare, default 20
+
+Options for gen:
+    --seed <n>        The same seed always gives the same input. Defaults to a random seed, which is printed to stderr
+    --size <n>        Roughly the number of lines or the side of the grid, default 100
+
+Options for diff:
+    --seed <n>        The seed of the first input, default 0. The following inputs use the next seeds
+    --count <n>       How many inputs to compare, default 100
+    --size <n>        The size of the inputs like for gen, default 20
+
+Options for fuzz:
+    --seed <n>        The seed of the first mutation, default 0. The following mutations use the next seeds
+    --count <n>       How many mutated inputs to parse, default 1000
+    --size <n>        The size of the generated input that is mutated along with the examples, default 10
+    --timeout <secs>  Treat parsing that takes longer as hanging, default 5
+
+Build without --release for fuzz to also catch arithmetic overflows.
+
+fetch and submit read the session cookie from AOC_SESSION in the environment or in .env.
+Set AOC_URL to use another server than https://adventofcode.com.
+";
+
+fn main() -> anyhow::Result<()> {
+    let mut args = Args::new(env::args().skip(1));
+    if args.flag("help") {
+        print!("{USAGE}");
+        return Ok(());
+    }
+
+    match args.positional().as_deref() {
+        Some("run") => run::run(args),
+        Some("bench") => bench::bench(args),
+        Some("diff") => diff::diff(args),
+        Some("fetch") => fetch::fetch(args),
+        Some("fuzz") => fuzz::fuzz(args),
+        Some("gen") => gen::generate(args),
+        Some("new") => new::new(args),
+        Some("submit") => submit::submit(args),
+        Some("verify") => verify::verify(args),
+        Some(other) => bail!("unknown command '{other}', see --help"),
+        None => bail!("missing command, see --help"),
+    }
+}
@@ -0,0 +1,154 @@
+//! The `new` command.
+
+use crate::args::Args;
+use crate::config;
+use anyhow::{bail, Context};
+use aoc::input;
+use std::fs::{self, OpenOptions};
+use std::io::ErrorKind;
+use std::path::Path;
+
+/// The solution that every new day starts from. `DAY` is replaced with the zero padded day,
+/// `NUMBER` with the day without padding and `YEAR` with the year.
+const TEMPLATE: &str = include_str!("../../../template.rs");
+
+/// The directory that contains the solutions, with one module per year.
+const DAYS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/days");
+
+pub fn new(mut args: Args) -> anyhow::Result<()> {
+    let year = config::year(&mut args)?;
+    let Some(day) = args.positional() else {
+        bail!("missing day to create, see --help");
+    };
+    let day: u8 = day
+        .parse()
+        .with_context(|| format!("invalid day '{day}'"))?;
+    args.finish()?;
+    if !(1..=25).contains(&day) {
+        bail!("day must be between 1 and 25, got {day}");
+    }
+
+    let days = Path::new(DAYS_DIR);
+    let year_dir = days.join(format!("y{year}"));
+    let file = year_dir.join(format!("day{day:02}.rs"));
+    if file.exists() {
+        bail!("'{}' already exists", file.display());
+    }
+
+    // A new year needs its own module, which is registered in the list of years.
+    let registry = year_dir.join("mod.rs");
+    let mut changes = Vec::new();
+    if registry.exists() {
+        let source = read(&registry)?;
+        changes.push((registry, register_day(&source, year, day)?));
+    } else {
+        let years = days.join("mod.rs");
+        let source = read(&years)?;
+        changes.push((years, register_year(&source, year)?));
+        changes.push((registry, new_year(year, day)));
+    }
+
+    let solution = TEMPLATE
+        .replace("DAY", &format!("{day:02}"))
+        .replace("NUMBER", &day.to_string())
+        .replace("YEAR", &year.to_string());
+    fs::create_dir_all(&year_dir)?;
+    fs::write(&file, solution).with_context(|| format!("could not write '{}'", file.display()))?;
+    println!("Created {}", file.display());
+    for (path, source) in changes {
+        fs::write(&path, source)
+            .with_context(|| format!("could not write '{}'", path.display()))?;
+        println!("Updated {}", path.display());
+    }
+
+    let dir = input::year_dir(year);
+    fs::create_dir_all(&dir)?;
+    for name in [format!("{day:02}"), format!("{day:02}-test")] {
+        let path = dir.join(name);
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(_) => println!("Created {}", path.display()),
+            Err(e) if e.kind() == ErrorKind::AlreadyExists => {}
+            Err(e) => {
+                return Err(e).with_context(|| format!("could not create '{}'", path.display()))
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn read(path: &Path) -> anyhow::Result<String> {
+    fs::read_to_string(path).with_context(|| format!("could not read '{}'", path.display()))
+}
+
+/// The module of a year that only contains the given day.
+fn new_year(year: u16, day: u8) -> String {
+    format!(
+        "//! The solutions of {year}.
+
+mod day{day:02};
+
+use crate::Day;
+
+/// All solved days of {year}, in order.
+pub const DAYS: &[Day] = &[
+    Day::new::<day{day:02}::Day{day:02}>({year}, {day}),
+];
+"
+    )
+}
+
+/// Add the module of a day and its entry in `DAYS` to the source of the module of its year, keeping both sorted.
+fn register_day(source: &str, year: u16, day: u8) -> anyhow::Result<String> {
+    let module = format!("mod day{day:02};");
+    let entry = format!("    Day::new::<day{day:02}::Day{day:02}>({year}, {day}),");
+    if source.lines().any(|line| line == module) {
+        bail!("day {day} is already registered in src/days/y{year}/mod.rs");
+    }
+
+    let mut lines: Vec<&str> = source.lines().collect();
+    insert_sorted(&mut lines, &module, "mod day")?;
+    insert_sorted(&mut lines, &entry, "    Day::new::<day")?;
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Add the module of a year to `src/days/mod.rs` and list its days in `YEARS`.
+fn register_year(source: &str, year: u16) -> anyhow::Result<String> {
+    let module = format!("mod y{year};");
+    let mut lines: Vec<&str> = source.lines().collect();
+    insert_sorted(&mut lines, &module, "mod y")?;
+
+    let years: Vec<String> = lines
+        .iter()
+        .filter_map(|line| line.strip_prefix("mod ")?.strip_suffix(';'))
+        .map(|module| format!("{module}::DAYS"))
+        .collect();
+    let Some(start) = lines
+        .iter()
+        .position(|line| line.starts_with("const YEARS"))
+    else {
+        bail!("no list of years in src/days/mod.rs");
+    };
+    let Some(end) = (start..lines.len()).find(|&i| lines[i].ends_with("];")) else {
+        bail!("the list of years in src/days/mod.rs does not end");
+    };
+    let list = format!("const YEARS: &[&[Day]] = &[{}];", years.join(", "));
+    lines.splice(start..=end, [list.as_str()]);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Insert a line into the block of lines that start with `prefix`, before the first line that sorts after it.
+fn insert_sorted<'a>(lines: &mut Vec<&'a str>, line: &'a str, prefix: &str) -> anyhow::Result<()> {
+    let block: Vec<usize> = (0..lines.len())
+        .filter(|&i| lines[i].starts_with(prefix))
+        .collect();
+    let Some(&last) = block.last() else {
+        bail!("no lines starting with '{}'", prefix.trim());
+    };
+    let i = block
+        .into_iter()
+        .find(|&i| lines[i] > line)
+        .unwrap_or(last + 1);
+    lines.insert(i, line);
+    Ok(())
+}
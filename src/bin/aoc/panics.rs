@@ -0,0 +1,20 @@
+//! Solutions that panic, for the commands that keep going or report them nicely.
+
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+
+/// Run a function and return the message if it panics.
+pub fn catch<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| message(&*payload))
+}
+
+/// The message of a panic, from the payload that `catch_unwind` or `JoinHandle::join` returned.
+pub fn message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
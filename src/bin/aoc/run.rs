@@ -0,0 +1,298 @@
+//! The `run` command.
+
+use crate::args::Args;
+use crate::config;
+use anyhow::{bail, Context};
+use aoc::artifact::{self, Sink};
+use aoc::input::{self, Input};
+use aoc::{answers, days, Day, Part, Solved};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use serde::Serialize;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// Where artifacts are saved in JSON mode, in a directory per year and day.
+const ARTIFACTS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/target/artifacts");
+
+/// Which inputs to solve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Source<'a> {
+    Real,
+    Example,
+    Path(&'a str),
+}
+
+/// How the answers are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Readable text. Artifacts are printed as well.
+    Text,
+    /// One JSON object per line and day. Artifacts are saved to files.
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            other => Err(format!("format must be text or json, got '{other}'")),
+        }
+    }
+}
+
+/// The JSON record of one day.
+#[derive(Serialize)]
+struct DayRecord<'a> {
+    year: u16,
+    day: u8,
+    input: &'a str,
+    parse_ns: u64,
+    parts: Vec<PartRecord>,
+}
+
+/// The JSON record of one part.
+#[derive(Serialize)]
+struct PartRecord {
+    part: u8,
+    answer: String,
+    #[serde(rename = "type")]
+    type_name: &'static str,
+    time_ns: u64,
+    artifacts: Vec<PathBuf>,
+}
+
+pub fn run(mut args: Args) -> anyhow::Result<()> {
+    let parts = match args.parsed::<Part>("part")? {
+        Some(part) => vec![part],
+        None => Part::BOTH.to_vec(),
+    };
+    let format = args.parsed("format")?.unwrap_or(Format::Text);
+    let jobs = args.parsed("jobs")?.unwrap_or(1);
+    let year = config::year(&mut args)?;
+    let example = args.flag("example");
+    let path = args.option("input")?;
+    let Some(days) = args.positional() else {
+        bail!("missing days to run, see --help");
+    };
+    let days = parse_days(year, &days)?;
+    args.finish()?;
+    if jobs == 0 {
+        bail!("--jobs must be at least 1");
+    }
+
+    let source = match (example, &path) {
+        (true, Some(_)) => bail!("--example and --input cannot be combined"),
+        (true, None) => Source::Example,
+        (false, Some(_)) if days.len() > 1 => bail!("--input can only be used with a single day"),
+        (false, Some(path)) => Source::Path(path),
+        (false, None) => Source::Real,
+    };
+
+    let start = Instant::now();
+    let cpu_start = cpu_time();
+    if jobs == 1 {
+        for &day in &days {
+            print!("{}", solve(day, source, &parts, format)?);
+        }
+    } else {
+        solve_parallel(&days, jobs, source, &parts, format)?;
+    }
+
+    if days.len() > 1 {
+        let wall = start.elapsed();
+        let cpu = match (cpu_start, cpu_time()) {
+            (Some(start), Some(end)) => format!("{:.2}s", (end - start).as_secs_f64()),
+            _ => "unknown".to_string(),
+        };
+        // Keep stdout parseable in JSON mode.
+        eprintln!(
+            "{} days in {:.2}s wall time, {cpu} CPU time",
+            days.len(),
+            wall.as_secs_f64()
+        );
+    }
+
+    Ok(())
+}
+
+/// Solve the days on `jobs` threads, in any order, but print them in order.
+#[cfg(feature = "parallel")]
+fn solve_parallel(
+    days: &[&Day],
+    jobs: usize,
+    source: Source,
+    parts: &[Part],
+    format: Format,
+) -> anyhow::Result<()> {
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(jobs).build()?;
+    let outputs: Vec<_> = pool.install(|| {
+        days.par_iter()
+            .map(|day| solve(day, source, parts, format))
+            .collect()
+    });
+    for output in outputs {
+        print!("{}", output?);
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "parallel"))]
+fn solve_parallel(
+    _days: &[&Day],
+    _jobs: usize,
+    _source: Source,
+    _parts: &[Part],
+    _format: Format,
+) -> anyhow::Result<()> {
+    bail!("--jobs needs the parallel feature, which this build was made without")
+}
+
+/// The CPU time that the process used so far, in all threads.
+#[cfg(unix)]
+fn cpu_time() -> Option<Duration> {
+    let mut usage = std::mem::MaybeUninit::<libc::rusage>::uninit();
+    // SAFETY: getrusage only writes to the given struct.
+    if unsafe { libc::getrusage(libc::RUSAGE_SELF, usage.as_mut_ptr()) } != 0 {
+        return None;
+    }
+    // SAFETY: getrusage succeeded, so it initialized the struct.
+    let usage = unsafe { usage.assume_init() };
+    let time = |t: libc::timeval| {
+        Duration::from_secs(t.tv_sec as u64) + Duration::from_micros(t.tv_usec as u64)
+    };
+    Some(time(usage.ru_utime) + time(usage.ru_stime))
+}
+
+#[cfg(not(unix))]
+fn cpu_time() -> Option<Duration> {
+    None
+}
+
+/// Solve one day and return what to print.
+///
+/// Answers for the real input are recorded if the part has none yet, so that `aoc verify` can check them later.
+/// Empty answers are not recorded, because they mean that the part found no answer.
+pub fn solve(day: &Day, source: Source, parts: &[Part], format: Format) -> anyhow::Result<String> {
+    let input = load(day, source)?;
+    let mut output = String::new();
+    let solved = match format {
+        Format::Text => {
+            let (solved, captured) = artifact::scope(Sink::Capture, || day.run(&input, parts));
+            let solved = solved?;
+            writeln!(output, "Day {:02}", day.number())?;
+            output.push_str(&captured);
+            for answer in &solved.answers {
+                writeln!(output, "Part {}: {}", answer.part, answer.value)?;
+            }
+            solved
+        }
+        Format::Json => {
+            let dir = Path::new(ARTIFACTS_DIR)
+                .join(day.year().to_string())
+                .join(format!("{:02}", day.number()));
+            let (solved, _) = artifact::scope(Sink::Save(dir), || day.run(&input, parts));
+            let solved = solved?;
+            writeln!(output, "{}", json(day, input.name(), &solved)?)?;
+            solved
+        }
+    };
+
+    if source == Source::Real {
+        let answers: Vec<String> = solved.answers.into_iter().map(|a| a.value).collect();
+        record(day, parts, &answers)?;
+    }
+
+    Ok(output)
+}
+
+/// The JSON record of a solved day, on a single line.
+fn json(day: &Day, input: &str, solved: &Solved) -> serde_json::Result<String> {
+    let parts = solved
+        .answers
+        .iter()
+        .map(|answer| PartRecord {
+            part: answer.part.number(),
+            answer: answer.value.clone(),
+            type_name: answer.type_name,
+            time_ns: answer.time.as_nanos() as u64,
+            artifacts: answer.artifacts.clone(),
+        })
+        .collect();
+    serde_json::to_string(&DayRecord {
+        year: day.year(),
+        day: day.number(),
+        input,
+        parse_ns: solved.parse.as_nanos() as u64,
+        parts,
+    })
+}
+
+/// Record the non-empty answers of parts that have none yet.
+fn record(day: &Day, parts: &[Part], answers: &[String]) -> anyhow::Result<()> {
+    let mut recorded = answers::load(day.year(), day.number())?;
+    let mut changed = false;
+    for (&part, answer) in parts.iter().zip(answers) {
+        if recorded.get(part).is_none() && !answer.is_empty() {
+            recorded.set(part, answer.as_str());
+            changed = true;
+        }
+    }
+    if changed {
+        let path = answers::path(day.year(), day.number());
+        answers::save(day.year(), day.number(), &recorded)
+            .with_context(|| format!("could not write answers to '{}'", path.display()))?;
+    }
+    Ok(())
+}
+
+/// Load the input of a day.
+pub fn load(day: &Day, source: Source) -> anyhow::Result<Input> {
+    let input = match source {
+        Source::Real => input::real(day.year(), day.number())?,
+        Source::Example => input::example(day.year(), day.number())?,
+        Source::Path("-") => input::stdin()?,
+        Source::Path(path) => input::path(path)?,
+    };
+    Ok(input)
+}
+
+/// Parse the days of a year: a single day (`5`), a range (`1..=10` or `1..10`), a list (`1,3,5`) or `all`.
+pub fn parse_days(year: u16, spec: &str) -> anyhow::Result<Vec<&'static Day>> {
+    if spec == "all" {
+        let days: Vec<_> = days::year(year).collect();
+        if days.is_empty() {
+            bail!("no days of {year} are solved");
+        }
+        return Ok(days);
+    }
+
+    let mut numbers = Vec::new();
+    for item in spec.split(',') {
+        let number = |s: &str| -> anyhow::Result<u8> {
+            s.parse().with_context(|| format!("invalid day '{s}'"))
+        };
+        if let Some((start, end)) = item.split_once("..=") {
+            numbers.extend(number(start)?..=number(end)?);
+        } else if let Some((start, end)) = item.split_once("..") {
+            numbers.extend(number(start)?..number(end)?);
+        } else {
+            numbers.push(number(item)?);
+        }
+    }
+
+    if numbers.is_empty() {
+        bail!("no days in '{spec}'");
+    }
+    numbers
+        .into_iter()
+        .map(|number| {
+            days::get(year, number).with_context(|| format!("day {number} of {year} is not solved"))
+        })
+        .collect()
+}
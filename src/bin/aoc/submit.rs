@@ -0,0 +1,75 @@
+//! The `submit` command.
+
+use crate::args::Args;
+use crate::client::Client;
+use crate::config;
+use crate::run::{self, Source};
+use anyhow::{bail, Context};
+use aoc::submit::{self, Response};
+use aoc::{answers, days, Part};
+
+pub fn submit(mut args: Args) -> anyhow::Result<()> {
+    let year = config::year(&mut args)?;
+    let (Some(day), Some(part)) = (args.positional(), args.positional()) else {
+        bail!("missing day and part to submit, see --help");
+    };
+    args.finish()?;
+    let number: u8 = day
+        .parse()
+        .with_context(|| format!("invalid day '{day}'"))?;
+    let day =
+        days::get(year, number).with_context(|| format!("day {number} of {year} is not solved"))?;
+    let part: Part = part.parse().map_err(anyhow::Error::msg)?;
+
+    let input = run::load(day, Source::Real)?;
+    let answer = day.solve(&input, &[part])?.remove(0);
+    println!("Day {number:02} part {part}: {answer}");
+    if answer.is_empty() {
+        bail!("the part found no answer");
+    }
+
+    let mut guesses = submit::load(year, number)?;
+    if let Some(reason) = guesses.check(part, &answer) {
+        bail!("not submitting, {reason}");
+    }
+
+    let client = Client::from_env()?;
+    let page = client.post(
+        &format!("/{year}/day/{number}/answer"),
+        &[("level", &part.to_string()), ("answer", &answer)],
+    )?;
+    let Some(response) = Response::parse(&page) else {
+        bail!("could not understand the response of the website");
+    };
+
+    match response {
+        Response::Correct => {
+            println!("That's the right answer");
+            let mut recorded = answers::load(year, number)?;
+            recorded.set(part, answer);
+            answers::save(year, number, &recorded)?;
+        }
+        Response::AlreadySolved => println!("This part is already solved"),
+        Response::Wait(time) => {
+            bail!(
+                "an answer was submitted too recently, try again in {}s",
+                time.as_secs()
+            )
+        }
+        Response::TooHigh | Response::TooLow | Response::Wrong => {
+            guesses.add(part, &answer, response);
+            let path = submit::path(year, number);
+            submit::save(year, number, &guesses)
+                .with_context(|| format!("could not write guesses to '{}'", path.display()))?;
+            // `aoc run` records answers before they are known to be right.
+            let mut recorded = answers::load(year, number)?;
+            if recorded.get(part) == Some(answer.as_str()) {
+                recorded.clear(part);
+                answers::save(year, number, &recorded)?;
+            }
+            bail!("the answer is {response}, logged in {}", path.display());
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+//! The `verify` command.
+
+use crate::args::Args;
+use crate::config;
+use crate::run::{self, Source};
+use anyhow::bail;
+use aoc::answers::{self, Verdict};
+use aoc::artifact::{self, Sink};
+use aoc::Part;
+
+pub fn verify(mut args: Args) -> anyhow::Result<()> {
+    let year = config::year(&mut args)?;
+    let days = match args.positional() {
+        Some(days) => run::parse_days(year, &days)?,
+        None => run::parse_days(year, "all")?,
+    };
+    args.finish()?;
+
+    // Artifacts would get mixed into the table.
+    artifact::set_sink(Sink::Discard);
+    println!("{:<4} {:<4} {:<7} Answer", "Day", "Part", "Result");
+    let mut failed = 0;
+    let mut missing = 0;
+    for day in days {
+        let recorded = answers::load(year, day.number())?;
+        let input = run::load(day, Source::Real)?;
+        let answers = day.solve(&input, &Part::BOTH)?;
+        for (part, answer) in Part::BOTH.into_iter().zip(answers) {
+            let (result, note) = match recorded.check(part, &answer) {
+                Verdict::Pass => ("pass", String::new()),
+                Verdict::Fail { expected } => {
+                    failed += 1;
+                    ("FAIL", format!(" (expected {expected})"))
+                }
+                // There is nothing to record either.
+                Verdict::Missing if answer.is_empty() => ("none", String::new()),
+                Verdict::Missing => {
+                    missing += 1;
+                    ("missing", String::new())
+                }
+            };
+            println!(
+                "{:<4} {:<4} {result:<7} {answer}{note}",
+                format!("{:02}", day.number()),
+                part.to_string(),
+            );
+        }
+    }
+
+    if missing > 0 {
+        println!("{missing} answers are not recorded, run the day once to record them");
+    }
+    if failed > 0 {
+        bail!("{failed} answers differ from the recorded ones");
+    }
+    Ok(())
+}
@@ -0,0 +1,214 @@
+//! Cycle detection for simulations that step deterministically from one state to the next.
+//!
+//! A simulation is given as a start state and a step function. The step function returns `None`
+//! when the simulation ends, like a guard leaving the map, so a sequence either ends or runs into a cycle.
+//! Both algorithms only keep two states at a time, so the states do not need to be hashable.
+//!
+//! <https://en.wikipedia.org/wiki/Cycle_detection>
+//!
+//! ```rust
+//! # use aoc::cycle;
+//! // 1, 2, 4, 8, 16, 32, 64, 28, 56, 12, 24, 48, 96, 92, 84, 68, 36, 72, 44, 88, 76, 52, 4, ...
+//! let double = |&n: &u32| Some(n * 2 % 100);
+//! let cycle = cycle::brent(1, double).unwrap();
+//! assert_eq!((cycle.start, cycle.length, cycle.state), (2, 20, 4));
+//! assert_eq!(cycle::nth_state(1, double, 1_000_000_000), Some(76));
+//! ```
+
+/// Where a sequence of states runs into a cycle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<S> {
+    /// The number of steps before the cycle starts.
+    pub start: usize,
+    /// The number of steps to go around the cycle once, at least 1.
+    pub length: usize,
+    /// The first state of the cycle, after `start` steps.
+    pub state: S,
+}
+
+impl<S> Cycle<S> {
+    /// The smallest number of steps that reaches the same state as `steps`.
+    ///
+    /// ```rust
+    /// # use aoc::cycle::Cycle;
+    /// let cycle = Cycle { start: 3, length: 5, state: () };
+    /// assert_eq!(cycle.reduce(2), 2);
+    /// assert_eq!(cycle.reduce(8), 3);
+    /// assert_eq!(cycle.reduce(1_000_000_000), 5);
+    /// ```
+    pub fn reduce(&self, steps: usize) -> usize {
+        if steps < self.start {
+            steps
+        } else {
+            self.start + (steps - self.start) % self.length
+        }
+    }
+}
+
+/// Find the cycle with Floyd's tortoise and hare, or return `None` if the sequence ends.
+///
+/// The hare moves two steps for every step of the tortoise, until they meet inside the cycle.
+/// Finding the start and the length takes more steps than [`brent`], but the first part is simpler.
+///
+/// ```rust
+/// # use aoc::cycle;
+/// let cycle = cycle::floyd(1, |&n: &u32| Some(n * 2 % 100)).unwrap();
+/// assert_eq!((cycle.start, cycle.length), (2, 20));
+/// assert_eq!(cycle::floyd(1, |&n: &u32| (n < 10).then_some(n + 1)), None);
+/// # let cycle = cycle::floyd(7, |&n: &u32| Some(n)).unwrap();
+/// # assert_eq!((cycle.start, cycle.length, cycle.state), (0, 1, 7));
+/// # let cycle = cycle::floyd(0, |&n: &u32| Some((n + 1) % 3)).unwrap();
+/// # assert_eq!((cycle.start, cycle.length, cycle.state), (0, 3, 0));
+/// # let cycle = cycle::floyd(0, |&n: &u32| Some(if n < 5 { n + 1 } else { 5 })).unwrap();
+/// # assert_eq!((cycle.start, cycle.length, cycle.state), (5, 1, 5));
+/// ```
+pub fn floyd<S>(start: S, mut step: impl FnMut(&S) -> Option<S>) -> Option<Cycle<S>>
+where
+    S: PartialEq + Clone,
+{
+    let mut hare = meet(&start, &mut step)?;
+
+    // The distance between them is now a multiple of the length of the cycle, so moving both
+    // one step at a time, with the tortoise starting over, they meet at the start of the cycle.
+    let mut tortoise = start;
+    let mut cycle_start = 0;
+    while tortoise != hare {
+        tortoise = following(&mut step, &tortoise);
+        hare = following(&mut step, &hare);
+        cycle_start += 1;
+    }
+
+    let mut length = 1;
+    let mut hare = following(&mut step, &tortoise);
+    while tortoise != hare {
+        hare = following(&mut step, &hare);
+        length += 1;
+    }
+    Some(Cycle {
+        start: cycle_start,
+        length,
+        state: tortoise,
+    })
+}
+
+/// Whether the sequence runs into a cycle instead of ending.
+///
+/// This stops as soon as the tortoise and the hare of [`floyd`] meet, without finding where the cycle
+/// starts and how long it is, so it takes the fewest steps when only a yes or no is needed.
+///
+/// ```rust
+/// # use aoc::cycle;
+/// assert!(cycle::has_cycle(1, |&n: &u32| Some(n * 2 % 100)));
+/// assert!(!cycle::has_cycle(1, |&n: &u32| (n < 10).then_some(n + 1)));
+/// # assert!(cycle::has_cycle(7, |&n: &u32| Some(n)));
+/// # assert!(!cycle::has_cycle(7, |_: &u32| None));
+/// ```
+pub fn has_cycle<S: PartialEq>(start: S, mut step: impl FnMut(&S) -> Option<S>) -> bool {
+    meet(&start, &mut step).is_some()
+}
+
+/// Move the tortoise one step and the hare two steps at a time until they meet inside the cycle,
+/// and return the state where they do. `None` if the sequence ends.
+fn meet<S: PartialEq>(start: &S, step: &mut impl FnMut(&S) -> Option<S>) -> Option<S> {
+    // The tortoise only visits states that the hare has visited, so only the hare can reach the end.
+    let mut tortoise = step(start)?;
+    let mut hare = step(&tortoise)?;
+    while tortoise != hare {
+        tortoise = following(step, &tortoise);
+        let ahead = step(&hare)?;
+        hare = step(&ahead)?;
+    }
+    Some(hare)
+}
+
+/// Find the cycle with Brent's algorithm, or return `None` if the sequence ends.
+///
+/// The tortoise waits at powers of two for the hare to come around, which finds the length first
+/// and usually takes fewer steps than [`floyd`].
+///
+/// ```rust
+/// # use aoc::cycle;
+/// let cycle = cycle::brent(1, |&n: &u32| Some(n * 2 % 100)).unwrap();
+/// assert_eq!((cycle.start, cycle.length), (2, 20));
+/// assert_eq!(cycle::brent(1, |&n: &u32| (n < 10).then_some(n + 1)), None);
+/// # let cycle = cycle::brent(7, |&n: &u32| Some(n)).unwrap();
+/// # assert_eq!((cycle.start, cycle.length, cycle.state), (0, 1, 7));
+/// # let cycle = cycle::brent(0, |&n: &u32| Some((n + 1) % 3)).unwrap();
+/// # assert_eq!((cycle.start, cycle.length, cycle.state), (0, 3, 0));
+/// # let cycle = cycle::brent(0, |&n: &u32| Some(if n < 5 { n + 1 } else { 5 })).unwrap();
+/// # assert_eq!((cycle.start, cycle.length, cycle.state), (5, 1, 5));
+/// ```
+pub fn brent<S>(start: S, mut step: impl FnMut(&S) -> Option<S>) -> Option<Cycle<S>>
+where
+    S: PartialEq + Clone,
+{
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(&start)?;
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare)?;
+        length += 1;
+    }
+
+    // With the hare one cycle ahead of the tortoise, they meet at the start of the cycle.
+    let mut tortoise = start;
+    let mut hare = tortoise.clone();
+    for _ in 0..length {
+        hare = following(&mut step, &hare);
+    }
+    let mut cycle_start = 0;
+    while tortoise != hare {
+        tortoise = following(&mut step, &tortoise);
+        hare = following(&mut step, &hare);
+        cycle_start += 1;
+    }
+    Some(Cycle {
+        start: cycle_start,
+        length,
+        state: tortoise,
+    })
+}
+
+/// The state after `n` steps, or `None` if the sequence ends before.
+///
+/// The cycle is found first, so this takes about as many steps as it takes to go around the cycle
+/// once, however large `n` is.
+///
+/// ```rust
+/// # use aoc::cycle;
+/// let count_to_ten = |&n: &u32| (n < 10).then_some(n + 1);
+/// assert_eq!(cycle::nth_state(0, count_to_ten, 7), Some(7));
+/// assert_eq!(cycle::nth_state(0, count_to_ten, 11), None);
+/// # assert_eq!(cycle::nth_state(0, count_to_ten, 10), Some(10));
+/// # let double = |&n: &u32| Some(n * 2 % 100);
+/// # for n in 0..50 {
+/// #     assert_eq!(cycle::nth_state(1, double, n), Some((0..n).fold(1, |n, _| n * 2 % 100)));
+/// # }
+/// ```
+pub fn nth_state<S>(start: S, mut step: impl FnMut(&S) -> Option<S>, n: usize) -> Option<S>
+where
+    S: PartialEq + Clone,
+{
+    let (mut state, steps) = match brent(start.clone(), &mut step) {
+        Some(cycle) if n >= cycle.start => {
+            let steps = (n - cycle.start) % cycle.length;
+            (cycle.state, steps)
+        }
+        _ => (start, n),
+    };
+    for _ in 0..steps {
+        state = step(&state)?;
+    }
+    Some(state)
+}
+
+/// The next state of a state that was followed by another one before, so it cannot be the end.
+fn following<S>(step: &mut impl FnMut(&S) -> Option<S>, state: &S) -> S {
+    step(state).expect("the step function is not deterministic")
+}
@@ -0,0 +1,30 @@
+//! The solutions of every year.
+//!
+//! Each year is a module like `y2024`, which lists its solved days in `DAYS`.
+
+mod y2024;
+
+use crate::Day;
+
+/// The solved days of every year, oldest year first.
+const YEARS: &[&[Day]] = &[y2024::DAYS];
+
+/// All solved days of all years, in order.
+pub fn all() -> impl Iterator<Item = &'static Day> {
+    YEARS.iter().flat_map(|days| days.iter())
+}
+
+/// All solved days of a year, in order.
+pub fn year(year: u16) -> impl Iterator<Item = &'static Day> {
+    all().filter(move |day| day.year() == year)
+}
+
+/// The years that have solutions, in order.
+pub fn years() -> impl Iterator<Item = u16> {
+    YEARS.iter().filter_map(|days| Some(days.first()?.year()))
+}
+
+/// Return the solution of a day, if it is solved.
+pub fn get(year: u16, number: u8) -> Option<&'static Day> {
+    all().find(|day| day.year() == year && day.number() == number)
+}
@@ -0,0 +1,55 @@
+use crate::random::Rng;
+use crate::*;
+use itertools::Itertools;
+use std::fmt::Display;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Parsed<'a> = (Vec<usize>, Vec<usize>);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        input.lines().map(parse_line).collect()
+    }
+
+    fn part1((left, right): &Self::Parsed<'_>) -> impl Display {
+        let pairs = left.iter().sorted().zip(right.iter().sorted());
+        pairs
+            .map(|(left, right)| left.abs_diff(*right))
+            .sum::<usize>()
+    }
+
+    fn part2((left, right): &Self::Parsed<'_>) -> impl Display {
+        left.iter().map(|&l| similarity(l, right)).sum::<usize>()
+    }
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(Part::One, "01-test", "11"),
+        Example::new(Part::Two, "01-test", "31"),
+    ];
+
+    /// `size` lines, where some numbers on the right also appear on the left.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let left: Vec<usize> = (0..size.max(1)).map(|_| rng.range(10000..=99999)).collect();
+        let mut input = String::new();
+        for &l in &left {
+            let r = if rng.chance(0.3) {
+                *rng.choose(&left)
+            } else {
+                rng.range(10000..=99999)
+            };
+            input += &format!("{l}   {r}\n");
+        }
+        Some(input)
+    }
+}
+
+fn similarity(left: usize, right: &[usize]) -> usize {
+    let count = right.iter().filter(|&&r| r == left).count();
+    count * left
+}
+
+fn parse_line(line: &str) -> Result<(usize, usize), ParseError> {
+    let [left, right] = parse::words(line)?;
+    Ok((parse::number(left)?, parse::number(right)?))
+}
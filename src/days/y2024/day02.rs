@@ -0,0 +1,85 @@
+use crate::random::Rng;
+use crate::*;
+use itertools::Itertools;
+use std::fmt::Display;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Parsed<'a> = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        input.lines().map(parse::ws_separated).collect()
+    }
+
+    fn part1(reports: &Self::Parsed<'_>) -> impl Display {
+        reports.iter().filter(|report| is_safe(report)).count()
+    }
+
+    fn part2(reports: &Self::Parsed<'_>) -> impl Display {
+        reports
+            .iter()
+            .filter(|report| is_dampened_safe(report))
+            .count()
+    }
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(Part::One, "02-test", "2"),
+        Example::new(Part::Two, "02-test", "4"),
+    ];
+
+    /// `size` reports. Most are safe, except for up to two random levels.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut input = String::new();
+        for _ in 0..size.max(1) {
+            let len = rng.range(5..=8);
+            let step = if rng.chance(0.5) { 1 } else { -1 };
+            let mut level: i64 = if step > 0 {
+                rng.range(1..=70)
+            } else {
+                rng.range(30..=99)
+            };
+            let mut report = Vec::new();
+            for _ in 0..len {
+                report.push(level);
+                level += step * rng.range(1..=3);
+            }
+            for _ in 0..rng.range(0..=2) {
+                let i = rng.index(len);
+                report[i] = rng.range(1..=99);
+            }
+            input += &report.iter().join(" ");
+            input.push('\n');
+        }
+        Some(input)
+    }
+}
+
+fn is_safe(report: &[i64]) -> bool {
+    let monotonic = decreasing(report) || increasing(report);
+    monotonic && not_steep(report)
+}
+
+fn is_dampened_safe(report: &[i64]) -> bool {
+    (0..report.len()).any(|i| {
+        let mut report = report.to_owned();
+        report.remove(i);
+        is_safe(&report)
+    })
+}
+
+fn increasing(report: &[i64]) -> bool {
+    gradient(report).all(|n| n > 0)
+}
+
+fn decreasing(report: &[i64]) -> bool {
+    gradient(report).all(|n| n < 0)
+}
+
+fn not_steep(report: &[i64]) -> bool {
+    gradient(report).all(|n| n.abs() <= 3)
+}
+
+fn gradient(report: &[i64]) -> impl Iterator<Item = i64> + use<'_> {
+    report.iter().copied().tuple_windows().map(|(a, b)| b - a)
+}
@@ -0,0 +1,107 @@
+use crate::random::Rng;
+use crate::*;
+use regex::{Captures, Regex};
+use std::fmt::Display;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Parsed<'a> = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let regex = Regex::new(r#"(mul\((\d+),(\d+)\)|don't\(\)|do\(\))"#).unwrap();
+        regex.captures_iter(input).map(parse_instruction).collect()
+    }
+
+    fn part1(instructions: &Self::Parsed<'_>) -> impl Display {
+        instructions
+            .iter()
+            .filter_map(|i| match i {
+                Instruction::Mul(a, b) => Some(a * b),
+                Instruction::Do | Instruction::Dont => None,
+            })
+            .sum::<i64>()
+    }
+
+    fn part2(instructions: &Self::Parsed<'_>) -> impl Display {
+        let mut sum = 0;
+        let mut enabled = true;
+        for instruction in instructions {
+            match instruction {
+                Instruction::Do => enabled = true,
+                Instruction::Dont => enabled = false,
+                Instruction::Mul(a, b) if enabled => {
+                    sum += a * b;
+                }
+                Instruction::Mul(_, _) => (),
+            }
+        }
+        sum
+    }
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(Part::One, "03-test", "161"),
+        Example::new(Part::Two, "03-test", "48"),
+    ];
+
+    /// `size` instructions in lines of corrupted memory, with things that look almost like instructions in between.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        const NOISE: &[&str] = &[
+            "mul(4*",
+            "mul[3,7]",
+            "mul ( 2 , 4 )",
+            "?(12,34)",
+            "mul(6,9!",
+            "mul(1234,5)",
+            "don't",
+            "do(",
+            "from()",
+            "what()",
+            "select(",
+            "mul(,7)",
+            "'",
+            "]",
+            "%",
+            "+",
+            "@",
+            "<",
+            "~",
+            " ",
+        ];
+        let mut input = String::new();
+        for i in 0..size.max(1) {
+            for _ in 0..rng.range(0..=4) {
+                input += *rng.choose(NOISE);
+            }
+            match rng.range(0..=5) {
+                0 => input += "do()",
+                1 => input += "don't()",
+                _ => input += &format!("mul({},{})", rng.range(1..=999), rng.range(1..=999)),
+            }
+            if i % 50 == 49 {
+                input.push('\n');
+            }
+        }
+        input.push('\n');
+        Some(input)
+    }
+}
+
+fn parse_instruction(c: Captures) -> Result<Instruction, ParseError> {
+    let instruction = if c[0].starts_with("mul") {
+        let a = parse::number(c.get(2).unwrap().as_str())?;
+        let b = parse::number(c.get(3).unwrap().as_str())?;
+        Instruction::Mul(a, b)
+    } else if c[0].starts_with("don") {
+        Instruction::Dont
+    } else {
+        Instruction::Do
+    };
+    Ok(instruction)
+}
+
+pub enum Instruction {
+    Do,
+    Dont,
+    Mul(i64, i64),
+}
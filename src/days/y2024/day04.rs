@@ -1,34 +1,49 @@
-use aoc::*;
+use crate::random::Rng;
+use crate::*;
 use glam::IVec2;
+use itertools::Itertools;
+use std::fmt::Display;
 
-const INPUT: &str = include_str!("../../input/04");
+pub struct Day04;
 
-fn main() {
-    assert_example!(part1, "04-test", 18);
-    println!("Part 1: {}", part1(INPUT));
-    assert_example!(part2, "04-test", 9);
-    println!("Part 2: {}", part2(INPUT));
-}
+impl Solution for Day04 {
+    type Parsed<'a> = Field;
 
-fn part1(input: &str) -> usize {
-    let field = parse(input);
-    count_over_field(&field, count_xmas_at)
-}
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let data = Grid::try_parse(input, Some)?;
+        Ok(Field { data })
+    }
 
-fn part2(input: &str) -> usize {
-    let field = parse(input);
-    count_over_field(&field, check_x_mas)
-}
+    fn part1(field: &Self::Parsed<'_>) -> impl Display {
+        count_over_field(field, count_xmas_at)
+    }
 
-fn count_over_field(field: &Field, count_at: fn(IVec2, &Field) -> usize) -> usize {
-    let mut found = 0;
-    for y in 0..field.data.len() {
-        for x in 0..field.data[y].len() {
-            let start = IVec2::new(x as i32, y as i32);
-            found += count_at(start, field);
+    fn part2(field: &Self::Parsed<'_>) -> impl Display {
+        count_over_field(field, check_x_mas)
+    }
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(Part::One, "04-test", "18"),
+        Example::new(Part::Two, "04-test", "9"),
+    ];
+
+    /// A grid of `size` by `size` letters of XMAS.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let size = size.clamp(1, 1000) as i32;
+        let mut grid = Grid::new(IVec2::splat(size), 'X');
+        for pos in grid.positions().collect_vec() {
+            grid[pos] = *rng.choose(&['X', 'M', 'A', 'S']);
         }
+        Some(grid.to_string())
     }
-    found
+}
+
+fn count_over_field(field: &Field, count_at: fn(IVec2, &Field) -> usize) -> usize {
+    field
+        .data
+        .positions()
+        .map(|start| count_at(start, field))
+        .sum()
 }
 
 fn check_x_mas(start: IVec2, field: &Field) -> usize {
@@ -69,21 +84,12 @@ fn check_xmas(start: IVec2, direction: IVec2, field: &Field) -> bool {
         && field.get(start + direction * 3) == 'S'
 }
 
-struct Field {
-    data: Vec<Vec<char>>,
+pub struct Field {
+    data: Grid<char>,
 }
 
 impl Field {
     fn get(&self, at: IVec2) -> char {
-        self.data
-            .get(at.y as usize)
-            .and_then(|row| row.get(at.x as usize))
-            .copied()
-            .unwrap_or('.')
+        self.data.get(at).copied().unwrap_or('.')
     }
 }
-
-fn parse(input: &str) -> Field {
-    let data = input.lines().map(|l| l.chars().collect()).collect();
-    Field { data }
-}
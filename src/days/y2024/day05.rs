@@ -0,0 +1,160 @@
+use crate::random::Rng;
+use crate::*;
+use itertools::Itertools;
+use std::fmt::Display;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Parsed<'a> = Puzzle;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Puzzle::parse(input)
+    }
+
+    fn part1(puzzle: &Self::Parsed<'_>) -> impl Display {
+        puzzle.part1()
+    }
+
+    fn part2(puzzle: &Self::Parsed<'_>) -> impl Display {
+        puzzle.part2()
+    }
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(Part::One, "05-test", "143"),
+        Example::new(Part::Two, "05-test", "123"),
+    ];
+
+    /// Up to 90 pages with rules for every pair, so that every update can be ordered, and `size` updates.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut pages = (10..=99).collect_vec();
+        rng.shuffle(&mut pages);
+        pages.truncate(size.clamp(3, 90));
+
+        let mut rules = pages.iter().tuple_combinations().collect_vec();
+        rng.shuffle(&mut rules);
+        let mut input = String::new();
+        for (before, after) in rules {
+            input += &format!("{before}|{after}\n");
+        }
+        input.push('\n');
+
+        for _ in 0..size.max(1) {
+            let len = rng.range(1..=pages.len().min(23).div_ceil(2)) * 2 - 1;
+            let mut update = pages.clone();
+            rng.shuffle(&mut update);
+            update.truncate(len);
+            if rng.chance(0.5) {
+                // Put the pages in the right order
+                update.sort_by_key(|page| pages.iter().position(|p| p == page));
+            }
+            input += &update.iter().join(",");
+            input.push('\n');
+        }
+        Some(input)
+    }
+}
+
+pub struct Puzzle {
+    rules: Rules,
+    updates: Vec<Update>,
+}
+
+impl Puzzle {
+    fn part1(&self) -> usize {
+        self.updates
+            .iter()
+            .filter(|u| u.valid(&self.rules))
+            .map(Update::middle)
+            .sum()
+    }
+
+    fn part2(&self) -> usize {
+        self.updates
+            .iter()
+            .filter(|u| !u.valid(&self.rules))
+            .cloned()
+            .map(|mut u| {
+                u.fix(&self.rules);
+                u.middle()
+            })
+            .sum()
+    }
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (rules, updates) = parse::split_once(input, "\n\n")?;
+        let rules = Rules::parse(rules)?;
+        let updates = updates
+            .lines()
+            .map(Update::parse)
+            .collect::<Result<_, _>>()?;
+        Ok(Self { rules, updates })
+    }
+}
+
+struct Rules(Vec<Rule>);
+
+impl Rules {
+    fn valid_order(&self, first: usize, second: usize) -> bool {
+        !self
+            .0
+            .iter()
+            .any(|&Rule(before, after)| after == first && before == second)
+    }
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let rules = input.lines().map(Rule::parse).collect::<Result<_, _>>()?;
+        Ok(Self(rules))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Rule(usize, usize);
+
+impl Rule {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (before, after) = parse::split_once(input, "|")?;
+        let before = parse::number(before)?;
+        let after = parse::number(after)?;
+        Ok(Self(before, after))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Update(Vec<usize>);
+
+impl Update {
+    fn valid(&self, rules: &Rules) -> bool {
+        for i in 0..self.0.len() - 1 {
+            for j in i + 1..self.0.len() {
+                if !rules.valid_order(self.0[i], self.0[j]) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    fn fix(&mut self, rules: &Rules) {
+        for i in 0..self.0.len() - 1 {
+            for j in i + 1..self.0.len() {
+                if !rules.valid_order(self.0[i], self.0[j]) {
+                    self.0.swap(i, j);
+                }
+            }
+        }
+    }
+
+    fn middle(&self) -> usize {
+        let middle = self.0.len() / 2;
+        self.0[middle]
+    }
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let update: Vec<usize> = input
+            .split(',')
+            .map(parse::number)
+            .collect::<Result<_, _>>()?;
+        Ok(Self(update))
+    }
+}
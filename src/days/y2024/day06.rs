@@ -0,0 +1,179 @@
+use crate::parallel::{self, prelude::*};
+use crate::random::Rng;
+use crate::*;
+use glam::IVec2;
+use itertools::Itertools;
+use std::fmt::Display;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Parsed<'a> = Lab;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Lab::parse(input)
+    }
+
+    fn part1(lab: &Self::Parsed<'_>) -> impl Display {
+        lab.clone().patrol().count_visited()
+    }
+
+    fn part2(lab: &Self::Parsed<'_>) -> impl Display {
+        let candidates: Vec<IVec2> = lab
+            .clone()
+            .patrol()
+            .tiles
+            .iter()
+            .filter_map(|(pos, tile)| match tile {
+                Tile::Visited => Some(pos),
+                _ => None,
+            })
+            .filter(|&modification| modification != lab.start)
+            .collect();
+        parallel::iter(candidates)
+            .filter(|&modification| {
+                let mut lab = lab.clone();
+                lab.place_wall(modification);
+                lab.loops()
+            })
+            .count()
+    }
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(Part::One, "06-test", "41"),
+        Example::new(Part::Two, "06-test", "6"),
+    ];
+
+    /// A lab of `size` by `size` tiles with scattered obstructions that the guard leaves.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let size = IVec2::splat(size.clamp(3, 1000) as i32);
+        loop {
+            let mut grid = Grid::new(size, '.');
+            for pos in grid.positions().collect_vec() {
+                if rng.chance(0.1) {
+                    grid[pos] = '#';
+                }
+            }
+            let start = rng.position(size);
+            // A guard surrounded by obstructions would turn forever.
+            for next in grid.neighbors4(start).collect_vec() {
+                grid[next] = '.';
+            }
+            grid[start] = '^';
+
+            let input = grid.to_string();
+            if Lab::parse(&input).is_ok() {
+                return Some(input);
+            }
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+enum Tile {
+    Start,
+    Empty,
+    Wall,
+    Visited,
+}
+
+impl Tile {
+    fn parse(input: char) -> Option<Self> {
+        match input {
+            '^' => Some(Tile::Start),
+            '.' => Some(Tile::Empty),
+            '#' => Some(Tile::Wall),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Lab {
+    start: IVec2,
+    tiles: Grid<Tile>,
+}
+
+impl Lab {
+    fn patrol(mut self) -> Self {
+        let mut pos = self.start;
+        let mut dir = Direction::North;
+
+        while self.contains(pos) {
+            self.mark_visited(pos);
+            (pos, dir) = self.advance(pos, dir);
+        }
+
+        self
+    }
+
+    fn loops(&self) -> bool {
+        if self.walled_in(self.start) {
+            return true;
+        }
+        let walk = |&(pos, dir): &(IVec2, Direction)| {
+            let (pos, dir) = self.advance(pos, dir);
+            self.contains(pos).then_some((pos, dir))
+        };
+        cycle::has_cycle((self.start, Direction::North), walk)
+    }
+
+    fn advance(&self, pos: IVec2, mut dir: Direction) -> (IVec2, Direction) {
+        while self.looking_at_wall(pos, dir) {
+            dir = dir.rotate_cw();
+        }
+        (pos + dir.vec(), dir)
+    }
+
+    /// Whether there are obstructions on all four sides. Only the start can be walled in, as the
+    /// guard walks onto every other tile from a free one.
+    fn walled_in(&self, pos: IVec2) -> bool {
+        Direction::ALL.iter().all(|&d| self.looking_at_wall(pos, d))
+    }
+
+    fn place_wall(&mut self, pos: IVec2) {
+        self.tiles[pos] = Tile::Wall;
+    }
+
+    fn mark_visited(&mut self, pos: IVec2) {
+        self.tiles[pos] = Tile::Visited;
+    }
+
+    fn contains(&self, position: IVec2) -> bool {
+        self.tiles.contains(position)
+    }
+
+    fn looking_at_wall(&self, position: IVec2, d: Direction) -> bool {
+        let check = position + d.vec();
+        self.tiles.get(check) == Some(&Tile::Wall)
+    }
+
+    fn count_visited(&self) -> usize {
+        self.tiles
+            .iter()
+            .filter(|(_, x)| matches!(x, Tile::Visited))
+            .count()
+    }
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let tiles = Grid::try_parse(input, Tile::parse)?;
+        let start = tiles
+            .find(|c| matches!(c, Tile::Start))
+            .ok_or_else(|| ParseError::end_of(input, "missing guard '^'"))?;
+        let lab = Self { tiles, start };
+        // Part 1 follows the guard until it leaves.
+        if lab.loops() {
+            let guard = &input[input.find('^').unwrap()..][..1];
+            return Err(ParseError::new(guard, "the guard never leaves the lab"));
+        }
+        Ok(lab)
+    }
+}
+
+#[test]
+fn test_guard_never_leaves() {
+    let error = Lab::parse(".#.\n#^#\n.#.\n").unwrap_err();
+    assert_eq!(error.to_string(), "the guard never leaves the lab: '^'");
+    assert!(Lab::parse(".#..\n...#\n#^..\n..#.\n").is_err());
+    assert!(Lab::parse("#..\n..#\n^..\n.#.\n").is_ok());
+}
@@ -0,0 +1,131 @@
+use crate::random::Rng;
+use crate::*;
+use itertools::Itertools;
+use std::collections::VecDeque;
+use std::fmt::Display;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Parsed<'a> = Vec<Equation>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        input.lines().map(parse_equation).collect()
+    }
+
+    fn part1(equations: &Self::Parsed<'_>) -> impl Display {
+        equations
+            .iter()
+            .filter(|e| e.is_possible(&[Operator::Add, Operator::Mul]))
+            .map(|e| e.target)
+            .sum::<i64>()
+    }
+
+    fn part2(equations: &Self::Parsed<'_>) -> impl Display {
+        equations
+            .iter()
+            .filter(|e| e.is_possible(&[Operator::Add, Operator::Mul, Operator::Concat]))
+            .map(|e| e.target)
+            .sum::<i64>()
+    }
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(Part::One, "07-test", "3749"),
+        Example::new(Part::Two, "07-test", "11387"),
+    ];
+
+    /// `size` equations. Most targets are the result of some operators, the others are off by one.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut input = String::new();
+        for _ in 0..size.max(1) {
+            let (target, numbers) = loop {
+                let len = rng.range(2..=12);
+                let numbers: Vec<i64> = (0..len)
+                    .map(|_| {
+                        if rng.chance(0.7) {
+                            rng.range(1..=9)
+                        } else {
+                            rng.range(10..=999)
+                        }
+                    })
+                    .collect();
+                let target =
+                    numbers[1..]
+                        .iter()
+                        .try_fold(numbers[0], |acc, &n| match rng.range(0..=2) {
+                            0 => acc.checked_add(n),
+                            1 => acc.checked_mul(n),
+                            _ => format!("{acc}{n}").parse().ok(),
+                        });
+                // Larger targets could overflow while concatenating.
+                match target {
+                    Some(target) if target < 10i64.pow(15) => break (target, numbers),
+                    _ => continue,
+                }
+            };
+            let target = target + rng.chance(0.3) as i64;
+            input += &format!("{target}: {}\n", numbers.iter().join(" "));
+        }
+        Some(input)
+    }
+}
+
+#[derive(Copy, Clone)]
+enum Operator {
+    Add,
+    Mul,
+    Concat,
+}
+
+impl Operator {
+    fn eval(self, a: i64, b: i64) -> i64 {
+        match self {
+            Operator::Add => a + b,
+            Operator::Mul => a * b,
+            Operator::Concat => concat(a, b),
+        }
+    }
+}
+
+pub struct Equation {
+    target: i64,
+    numbers: Vec<i64>,
+}
+
+impl Equation {
+    fn is_possible(&self, operators: &[Operator]) -> bool {
+        let mut numbers = VecDeque::from(self.numbers.clone());
+        let acc = numbers.pop_front().unwrap();
+        is_possible(operators, self.target, acc, numbers)
+    }
+}
+
+fn is_possible(
+    operators: &[Operator],
+    target: i64,
+    acc: i64,
+    mut remaining: VecDeque<i64>,
+) -> bool {
+    if target == acc && remaining.is_empty() {
+        return true;
+    } else if acc > target || remaining.is_empty() {
+        return false;
+    }
+
+    let next = remaining.pop_front().unwrap();
+
+    operators.iter().any(|&op| {
+        let acc = op.eval(acc, next);
+        is_possible(operators, target, acc, remaining.clone())
+    })
+}
+
+fn parse_equation(input: &str) -> Result<Equation, ParseError> {
+    let (target, numbers) = parse::split_once(input, ": ")?;
+    let target = parse::number(target)?;
+    let numbers = parse::ws_separated(numbers)?;
+    if numbers.is_empty() {
+        return Err(ParseError::end_of(input, "expected numbers"));
+    }
+    Ok(Equation { target, numbers })
+}
@@ -0,0 +1,88 @@
+use crate::random::Rng;
+use crate::*;
+use glam::IVec2;
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Parsed<'a> = (HashMap<char, Vec<IVec2>>, IVec2);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let grid = Grid::try_parse(input, |c| {
+            (c == '.' || c.is_ascii_alphanumeric()).then_some(c)
+        })?;
+        let antennas = grid.iter().filter(|&(_, &c)| c != '.');
+
+        let mut map: HashMap<char, Vec<IVec2>> = HashMap::new();
+        for (pos, &c) in antennas {
+            map.entry(c).or_default().push(pos);
+        }
+
+        Ok((map, grid.size()))
+    }
+
+    fn part1((map, grid_size): &Self::Parsed<'_>) -> impl Display {
+        let mut antinodes = HashSet::new();
+
+        for antennas in map.values() {
+            for (&a, &b) in antennas.iter().tuple_combinations() {
+                let distance = b - a;
+                let antinode = b + distance;
+                if grid_contains(antinode, *grid_size) {
+                    antinodes.insert(antinode);
+                }
+                let antinode = a - distance;
+                if grid_contains(antinode, *grid_size) {
+                    antinodes.insert(antinode);
+                }
+            }
+        }
+
+        antinodes.len()
+    }
+
+    fn part2((map, grid_size): &Self::Parsed<'_>) -> impl Display {
+        let mut antinodes = HashSet::new();
+        const RESONANCE: i32 = 50;
+
+        for antennas in map.values() {
+            for (&a, &b) in antennas.iter().tuple_combinations() {
+                let distance = b - a;
+                for r in -RESONANCE..RESONANCE {
+                    let antinode = b + distance * r;
+                    if grid_contains(antinode, *grid_size) {
+                        antinodes.insert(antinode);
+                    }
+                }
+            }
+        }
+
+        antinodes.len()
+    }
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(Part::One, "08-test", "14"),
+        Example::new(Part::Two, "08-test", "34"),
+    ];
+
+    /// A map of `size` by `size` tiles with antennas of about `size / 3` frequencies.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let frequencies = ('0'..='9').chain('a'..='z').chain('A'..='Z').collect_vec();
+        let frequencies = &frequencies[..(size / 3).clamp(1, frequencies.len())];
+        let size = size.clamp(1, 1000) as i32;
+        let mut grid = Grid::new(IVec2::splat(size), '.');
+        for pos in grid.positions().collect_vec() {
+            if rng.chance(0.05) {
+                grid[pos] = *rng.choose(frequencies);
+            }
+        }
+        Some(grid.to_string())
+    }
+}
+
+fn grid_contains(point: IVec2, size: IVec2) -> bool {
+    point.x >= 0 && point.y >= 0 && point.x < size.x && point.y < size.y
+}
@@ -0,0 +1,191 @@
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::fmt::Display;
+
+use crate::random::Rng;
+use crate::*;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Parsed<'a> = VecDeque<Block>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let mut position = 0;
+        let mut result = VecDeque::new();
+        let lengths = parse::chars(input.trim(), |c| c.to_digit(10))?;
+        for (i, length) in lengths.into_iter().map(|l| l as usize).enumerate() {
+            let block = if i % 2 == 0 {
+                Block::File(File {
+                    position,
+                    id: i / 2,
+                    length,
+                })
+            } else {
+                Block::Free { position, length }
+            };
+            position += length;
+            result.push_back(block);
+        }
+        Ok(result)
+    }
+
+    fn part1(disk_map: &Self::Parsed<'_>) -> impl Display {
+        let mut disk_map = disk_map.clone();
+        let mut map_index = 0;
+        let mut checksum = 0;
+
+        'checksum: while let Some(block) = disk_map.pop_front() {
+            match block {
+                Block::Free {
+                    length: mut free_space,
+                    ..
+                } => {
+                    // Fill free space
+                    let mut fill = Vec::new();
+                    while free_space > 0 {
+                        let Some(tail) = disk_map.pop_back() else {
+                            break 'checksum;
+                        };
+
+                        match tail {
+                            Block::Free { .. } => (), // Remove free space from the end
+                            Block::File(File {
+                                id,
+                                length,
+                                position,
+                            }) => {
+                                let insert_length = free_space.min(length);
+                                free_space -= insert_length;
+                                let remaining_length = length - insert_length;
+                                fill.push(Block::File(File {
+                                    position,
+                                    id,
+                                    length: insert_length,
+                                }));
+                                if remaining_length > 0 {
+                                    disk_map.push_back(Block::File(File {
+                                        position,
+                                        id,
+                                        length: remaining_length,
+                                    }));
+                                }
+                            }
+                        }
+                    }
+                    for block in fill.into_iter().rev() {
+                        disk_map.push_front(block);
+                    }
+                }
+                Block::File(File { id, length, .. }) => {
+                    for _ in 0..length {
+                        checksum += id * map_index;
+                        map_index += 1;
+                    }
+                }
+            }
+        }
+
+        checksum
+    }
+
+    fn part2(disk: &Self::Parsed<'_>) -> impl Display {
+        let mut disk = disk.clone();
+        let mut moved = Vec::new();
+
+        while let Some(block) = disk.pop_back() {
+            match block {
+                Block::Free { .. } => (), // Empty space can be ignored
+                Block::File(File {
+                    position: file_position,
+                    id: file_id,
+                    length: file_length,
+                }) => {
+                    // Find free space to put file
+                    let mut space = None;
+                    for (i, &block) in disk.iter().enumerate() {
+                        if let Block::Free { position, length } = block {
+                            if length >= file_length {
+                                space = Some((i, position, length));
+                                break;
+                            }
+                        }
+                    }
+
+                    let Some((space_index, space_position, space_length)) = space else {
+                        // No space for the file, cannot move further
+                        moved.push(File {
+                            id: file_id,
+                            position: file_position,
+                            length: file_length,
+                        });
+                        continue;
+                    };
+
+                    // Move file here
+                    moved.push(File {
+                        id: file_id,
+                        position: space_position,
+                        length: file_length,
+                    });
+
+                    // Adjust remaining space
+                    match space_length.cmp(&file_length) {
+                        Ordering::Equal => {
+                            disk.remove(space_index);
+                        }
+                        Ordering::Greater => {
+                            disk[space_index] = Block::Free {
+                                position: space_position + file_length,
+                                length: space_length - file_length,
+                            };
+                        }
+                        Ordering::Less => panic!("this should not be possible"),
+                    }
+                }
+            }
+        }
+
+        let mut checksum = 0;
+        for file in moved {
+            for i in file.position..(file.position + file.length) {
+                checksum += i * file.id;
+            }
+        }
+        checksum
+    }
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(Part::One, "09-test", "1928"),
+        Example::new(Part::Two, "09-test", "2858"),
+    ];
+
+    /// A disk map of `size` digits, which ends with free space if `size` is even.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut input: String = (0..size.max(1))
+            .map(|i| {
+                let length = if i % 2 == 0 {
+                    rng.range(1..=9)
+                } else {
+                    rng.range(0..=9)
+                };
+                char::from_digit(length, 10).unwrap()
+            })
+            .collect();
+        input.push('\n');
+        Some(input)
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct File {
+    position: usize,
+    id: usize,
+    length: usize,
+}
+
+#[derive(Copy, Clone)]
+pub enum Block {
+    Free { position: usize, length: usize },
+    File(File),
+}
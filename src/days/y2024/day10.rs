@@ -0,0 +1,122 @@
+use crate::random::Rng;
+use crate::*;
+use glam::IVec2;
+use itertools::Itertools;
+use std::collections::HashSet;
+use std::fmt::Display;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed<'a> = Map;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Map::parse(input)
+    }
+
+    fn part1(map: &Self::Parsed<'_>) -> impl Display {
+        map.trailheads()
+            .map(|t| map.reachable(t).len())
+            .sum::<usize>()
+    }
+
+    fn part2(map: &Self::Parsed<'_>) -> impl Display {
+        map.trailheads()
+            .map(|t| map.distinct_trails(t))
+            .sum::<usize>()
+    }
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(Part::One, "10-test", "36"),
+        Example::new(Part::Two, "10-test", "81"),
+    ];
+
+    /// A map of `size` by `size` heights with random trails and random heights in between.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let size = IVec2::splat(size.clamp(2, 1000) as i32);
+        let mut heights = Grid::new(size, None);
+        for _ in 0..(size.x * size.y / 20).max(1) {
+            let mut pos = rng.position(size);
+            for height in 0..=9 {
+                if heights[pos].is_some_and(|h| h != height) {
+                    break;
+                }
+                heights[pos] = Some(height);
+                let next = heights.neighbors4(pos).collect_vec();
+                pos = *rng.choose(&next);
+            }
+        }
+        let map =
+            heights.map(|&h| char::from_digit(h.unwrap_or_else(|| rng.range(0..=9)), 10).unwrap());
+        Some(map.to_string())
+    }
+}
+
+#[derive(Debug)]
+pub struct Map {
+    height: Grid<i64>,
+}
+
+impl Map {
+    fn reachable(&self, from: IVec2) -> HashSet<IVec2> {
+        let from_height = match self.get_height(from) {
+            Some(9) => return [from].into(),
+            None => return [].into(),
+            Some(h) => h,
+        };
+
+        self.climb_up(from, from_height)
+            .into_iter()
+            .map(|pos| self.reachable(pos))
+            .fold(HashSet::new(), |mut acc, other| {
+                acc.extend(other);
+                acc
+            })
+    }
+
+    fn distinct_trails(&self, from: IVec2) -> usize {
+        let from_height = match self.get_height(from) {
+            Some(9) => return 1,
+            None => return 0,
+            Some(h) => h,
+        };
+
+        self.climb_up(from, from_height)
+            .into_iter()
+            .map(|pos| self.distinct_trails(pos))
+            .sum()
+    }
+
+    fn climb_up(&self, from: IVec2, from_height: i64) -> Vec<IVec2> {
+        DIRECTIONS4
+            .into_iter()
+            .filter_map(|dir| {
+                let pos = from + dir;
+                self.get_height(pos).map(|height| (pos, height))
+            })
+            .filter_map(|(pos, h)| {
+                if h == from_height + 1 {
+                    Some(pos)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    fn get_height(&self, at: IVec2) -> Option<i64> {
+        self.height.get(at).copied()
+    }
+
+    fn trailheads(&self) -> impl Iterator<Item = IVec2> + use<'_> {
+        self.height.iter().filter_map(|(pos, &h)| match h {
+            0 => Some(pos),
+            _ => None,
+        })
+    }
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let height = Grid::try_parse(input, |c| c.to_digit(10).map(i64::from))?;
+        Ok(Self { height })
+    }
+}
@@ -1,24 +1,39 @@
-use aoc::*;
+use crate::random::Rng;
+use crate::*;
 use cached::proc_macro::cached;
+use itertools::Itertools;
+use std::fmt::Display;
 
-const INPUT: &str = include_str!("../../input/11");
+pub struct Day11;
 
-fn main() {
-    assert_example!(part1, "11-test", 55312);
-    println!("Part 1: {}", part1(INPUT));
-    println!("Part 2: {}", part2(INPUT));
-}
+impl Solution for Day11 {
+    type Parsed<'a> = Vec<usize>;
 
-fn part1(input: &str) -> usize {
-    parse_ws_separated(input)
-        .map(|stone| blink(stone, 25))
-        .sum()
-}
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse::ws_separated(input)
+    }
+
+    fn part1(stones: &Self::Parsed<'_>) -> impl Display {
+        stones.iter().map(|&stone| blink(stone, 25)).sum::<usize>()
+    }
 
-fn part2(input: &str) -> usize {
-    parse_ws_separated(input)
-        .map(|stone| blink(stone, 75))
-        .sum()
+    fn part2(stones: &Self::Parsed<'_>) -> impl Display {
+        stones.iter().map(|&stone| blink(stone, 75)).sum::<usize>()
+    }
+
+    const EXAMPLES: &'static [Example] = &[Example::new(Part::One, "11-test", "55312")];
+
+    /// Up to `size` stones, among them a few zeros and numbers with an even number of digits.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut input = (0..size.clamp(1, 100))
+            .map(|_| match rng.range(0..=3) {
+                0 => 0,
+                _ => rng.range(1..=9_999_999),
+            })
+            .join(" ");
+        input.push('\n');
+        Some(input)
+    }
 }
 
 #[cached]
@@ -38,7 +53,7 @@ fn blink(stone: usize, times: usize) -> usize {
 }
 
 fn even_digits(n: usize) -> bool {
-    digits(n) % 2 == 0
+    digits(n).is_multiple_of(2)
 }
 
 fn digits(n: usize) -> usize {
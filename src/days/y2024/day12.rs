@@ -0,0 +1,74 @@
+mod reference;
+
+use crate::random::Rng;
+use crate::regions::{self, Regions};
+use crate::*;
+use glam::IVec2;
+use itertools::Itertools;
+use std::fmt::Display;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Parsed<'a> = Garden;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let plants = Grid::try_parse(input, |c| c.is_ascii_uppercase().then_some(c))?;
+        let regions = regions::label(&plants, |a, b| a == b);
+        Ok(Garden { plants, regions })
+    }
+
+    fn part1(garden: &Self::Parsed<'_>) -> impl Display {
+        garden
+            .regions
+            .iter()
+            .map(|region| region.area() * region.perimeter)
+            .sum::<usize>()
+    }
+
+    fn part2(garden: &Self::Parsed<'_>) -> impl Display {
+        garden
+            .regions
+            .iter()
+            .map(|region| region.area() * region.sides)
+            .sum::<usize>()
+    }
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(Part::One, "12-test", "140"),
+        Example::new(Part::Two, "12-test", "80"),
+    ];
+
+    fn reference(garden: &Self::Parsed<'_>, part: Part) -> Option<String> {
+        Some(match part {
+            Part::One => reference::part1(&garden.plants).to_string(),
+            Part::Two => reference::part2(&garden.plants).to_string(),
+        })
+    }
+
+    /// A garden of `size` by `size` plots, where plants spread to grow regions of all shapes, some with holes.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let plants = ('A'..='Z').take((size / 4).clamp(2, 26)).collect_vec();
+        let size = IVec2::splat(size.clamp(1, 1000) as i32);
+        let mut garden = Grid::new(size, 'A');
+        for pos in garden.positions().collect_vec() {
+            garden[pos] = *rng.choose(&plants);
+        }
+        for _ in 0..3 {
+            for pos in garden.positions().collect_vec() {
+                let neighbors = garden.neighbors4(pos).collect_vec();
+                if !neighbors.is_empty() {
+                    garden[pos] = garden[*rng.choose(&neighbors)];
+                }
+            }
+        }
+        Some(garden.to_string())
+    }
+}
+
+/// The plants, which the reference solutions measure on their own, and their regions.
+#[derive(Clone, Debug)]
+pub struct Garden {
+    plants: Grid<char>,
+    regions: Regions,
+}
@@ -0,0 +1,63 @@
+//! Slow but obviously correct solutions, see [`Solution::reference`](crate::Solution::reference).
+
+use crate::{Grid, DIRECTIONS4};
+use glam::IVec2;
+use std::collections::HashSet;
+
+pub fn part1(plants: &Grid<char>) -> usize {
+    regions(plants)
+        .iter()
+        .map(|region| region.len() * fences(region).len())
+        .sum()
+}
+
+pub fn part2(plants: &Grid<char>) -> usize {
+    regions(plants)
+        .iter()
+        .map(|region| region.len() * sides(region))
+        .sum()
+}
+
+/// Grow a region from every plot that is not in one yet, one plot with the same plant at a time.
+fn regions(plants: &Grid<char>) -> Vec<HashSet<IVec2>> {
+    let mut seen = HashSet::new();
+    let mut regions = Vec::new();
+    for start in plants.positions() {
+        if !seen.insert(start) {
+            continue;
+        }
+        let plant = plants.get(start);
+        let mut region = HashSet::from([start]);
+        let mut todo = vec![start];
+        while let Some(plot) = todo.pop() {
+            for dir in DIRECTIONS4 {
+                let next = plot + dir;
+                if plants.get(next) == plant && seen.insert(next) {
+                    region.insert(next);
+                    todo.push(next);
+                }
+            }
+        }
+        regions.push(region);
+    }
+    regions
+}
+
+/// Every edge of a plot that borders another region, as the plot and the direction to the outside.
+fn fences(plots: &HashSet<IVec2>) -> HashSet<(IVec2, IVec2)> {
+    plots
+        .iter()
+        .flat_map(|&plot| DIRECTIONS4.map(|dir| (plot, dir)))
+        .filter(|&(plot, dir)| !plots.contains(&(plot + dir)))
+        .collect()
+}
+
+/// Count the fences that do not continue the fence of the next plot along the same side.
+/// Every side has exactly one of them at its end.
+fn sides(plots: &HashSet<IVec2>) -> usize {
+    let fences = fences(plots);
+    fences
+        .iter()
+        .filter(|&&(plot, dir)| !fences.contains(&(plot + dir.perp(), dir)))
+        .count()
+}
@@ -0,0 +1,120 @@
+mod reference;
+
+use crate::random::Rng;
+use crate::*;
+use glam::I64Vec2;
+use regex::Regex;
+use std::fmt::Display;
+use std::sync::LazyLock;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Parsed<'a> = Vec<ClawMachine>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        input.split("\n\n").map(ClawMachine::parse).collect()
+    }
+
+    fn part1(machines: &Self::Parsed<'_>) -> impl Display {
+        machines.iter().map(|c| c.tokens_to_win(100)).sum::<i64>()
+    }
+
+    fn part2(machines: &Self::Parsed<'_>) -> impl Display {
+        machines
+            .iter()
+            .map(|&c| c.fix_conversion_error())
+            .map(|c| c.tokens_to_win(i64::MAX))
+            .sum::<i64>()
+    }
+
+    const EXAMPLES: &'static [Example] = &[Example::new(Part::One, "13-test", "480")];
+
+    /// Part 2 needs too many presses to try them all.
+    fn reference(machines: &Self::Parsed<'_>, part: Part) -> Option<String> {
+        match part {
+            Part::One => Some(reference::part1(machines).to_string()),
+            Part::Two => None,
+        }
+    }
+
+    /// `size` claw machines. About half of the prizes can be won,
+    /// and a few machines have buttons that move in the same direction.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut machines = Vec::new();
+        for _ in 0..size.max(1) {
+            let (a, b) = if rng.chance(0.05) {
+                let direction = I64Vec2::new(rng.range(1..=20), rng.range(1..=20));
+                (direction * rng.range(1..=4), direction * rng.range(1..=4))
+            } else {
+                let a = I64Vec2::new(rng.range(10..=99), rng.range(10..=99));
+                (a, I64Vec2::new(rng.range(10..=99), rng.range(10..=99)))
+            };
+            let prize = if rng.chance(0.5) {
+                a * rng.range(0..=100) + b * rng.range(0..=100)
+            } else {
+                I64Vec2::new(rng.range(1000..=20000), rng.range(1000..=20000))
+            };
+            machines.push(format!(
+                "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                a.x, a.y, b.x, b.y, prize.x, prize.y
+            ));
+        }
+        Some(machines.join("\n"))
+    }
+}
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub struct ClawMachine {
+    a: I64Vec2,
+    b: I64Vec2,
+    prize: I64Vec2,
+}
+
+/// How far off the prizes are in part 2.
+const CONVERSION_ERROR: i64 = 10000000000000;
+
+impl ClawMachine {
+    fn fix_conversion_error(self) -> Self {
+        Self {
+            prize: self.prize + I64Vec2::splat(CONVERSION_ERROR),
+            ..self
+        }
+    }
+
+    /// The fewest tokens to win the prize with at most `limit` presses of each button, or 0 if it cannot be won.
+    fn tokens_to_win(self, limit: i64) -> i64 {
+        let presses = linalg::cheapest_combination(
+            &self.a.to_array(),
+            &self.b.to_array(),
+            &self.prize.to_array(),
+            (3, 1),
+            limit,
+        );
+        presses.map_or(0, |(a, b)| a * 3 + b)
+    }
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let captures = REGEX
+            .captures(input)
+            .ok_or_else(|| ParseError::new(input, "expected a claw machine"))?;
+        let number = |i| parse::number(captures.get(i).unwrap().as_str());
+        // Part 2 moves the prize by the conversion error.
+        let prize = |i| {
+            let text = captures.get(i).unwrap().as_str();
+            parse::number(text)
+                .ok()
+                .filter(|&n: &i64| n.checked_add(CONVERSION_ERROR).is_some())
+                .ok_or_else(|| ParseError::new(text, "prize is too far away"))
+        };
+        Ok(Self {
+            a: I64Vec2::new(number(1)?, number(2)?),
+            b: I64Vec2::new(number(3)?, number(4)?),
+            prize: I64Vec2::new(prize(5)?, prize(6)?),
+        })
+    }
+}
+
+static REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"X\+(\d+), Y\+(\d+)\n.*X\+(\d+), Y\+(\d+)\n.*X=(\d+), Y=(\d+)").unwrap()
+});
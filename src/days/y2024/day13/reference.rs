@@ -0,0 +1,18 @@
+//! Slow but obviously correct solutions, see [`Solution::reference`](crate::Solution::reference).
+
+use super::ClawMachine;
+use itertools::Itertools;
+
+/// Try every number of presses of both buttons.
+pub fn part1(machines: &[ClawMachine]) -> i64 {
+    machines
+        .iter()
+        .filter_map(|machine| {
+            (0..=100)
+                .cartesian_product(0..=100)
+                .filter(|&(a, b)| a * machine.a + b * machine.b == machine.prize)
+                .map(|(a, b)| a * 3 + b)
+                .min()
+        })
+        .sum()
+}
@@ -0,0 +1,207 @@
+use crate::random::Rng;
+use crate::*;
+use glam::IVec2;
+use itertools::Itertools;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::fmt::{self, Display};
+
+pub struct Day14;
+
+/// The last row of the tree is this many robots wide.
+const TREE_WIDTH: usize = 31;
+
+/// The size of the area the robots move in.
+const AREA: IVec2 = IVec2::new(101, 103);
+
+// Part 2 combines the seconds of the rows and the columns, which needs sides without common factors.
+const _: () = assert!(matches!(
+    math::checked_gcd(AREA.x as i64, AREA.y as i64),
+    Some(1)
+));
+
+impl Solution for Day14 {
+    type Parsed<'a> = Vec<Robot>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        input.lines().map(parse_line).collect()
+    }
+
+    fn part1(robots: &Self::Parsed<'_>) -> impl Display {
+        let mut area = Area {
+            robots: robots.clone(),
+            size: AREA,
+        };
+        for _ in 0..100 {
+            area.step();
+        }
+        area.safety_factor()
+    }
+
+    fn part2(robots: &Self::Parsed<'_>) -> impl Display {
+        let size = AREA;
+
+        // The tree is found by its widest row. Which robots are in a row only depends on the seconds
+        // modulo the height, and where they are in the row on the seconds modulo the width.
+        // So try both separately, and combine the seconds at which a row is wide enough.
+        let period = size.x * size.y;
+        let mut seconds = Vec::new();
+        for y_seconds in 0..size.y {
+            let rows = robots
+                .iter()
+                .into_group_map_by(|robot| robot.after(y_seconds, size).y);
+            for row in rows.values().filter(|row| row.len() >= TREE_WIDTH) {
+                for x_seconds in 0..size.x {
+                    let columns = row.iter().map(|robot| robot.after(x_seconds, size).x);
+                    if longest_run(columns, size.x) >= TREE_WIDTH {
+                        let x = (x_seconds as i64, size.x as i64);
+                        let y = (y_seconds as i64, size.y as i64);
+                        let (combined, _) = math::crt([x, y]).expect("the sides are coprime");
+                        seconds.push(combined as i32);
+                    }
+                }
+            }
+        }
+        // At 0 seconds the robots have not moved yet, the same picture comes again after the period.
+        let seconds = seconds
+            .into_iter()
+            .map(|seconds| if seconds == 0 { period } else { seconds })
+            .min();
+        // Like the missing second part of the last day, an empty answer is no answer.
+        let Some(seconds) = seconds else {
+            return String::new();
+        };
+
+        let area = Area {
+            robots: robots
+                .iter()
+                .map(|robot| Robot {
+                    p: robot.after(seconds, size),
+                    v: robot.v,
+                })
+                .collect(),
+            size,
+        };
+        artifact::emit("robots.txt", &area);
+        seconds.to_string()
+    }
+
+    /// A tree of robots that forms after a random number of seconds, and `size` robots that are not part of it.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let area = AREA;
+        let seconds = rng.range(1..=area.x * area.y - 1);
+        // The last row of the tree is 31 robots wide.
+        let top = IVec2::new(rng.range(15..=area.x - 16), rng.range(0..=area.y - 16));
+        let mut targets = Vec::new();
+        for row in 0..16 {
+            for x in -row..=row {
+                targets.push(top + IVec2::new(x, row));
+            }
+        }
+        for _ in 0..size {
+            targets.push(IVec2::new(
+                rng.range(0..=area.x - 1),
+                rng.range(0..=area.y - 1),
+            ));
+        }
+        rng.shuffle(&mut targets);
+
+        let mut input = String::new();
+        for target in targets {
+            let v = IVec2::new(rng.range(-100..=100), rng.range(-100..=100));
+            let p = (target - v * seconds).rem_euclid(area);
+            input += &format!("p={},{} v={},{}\n", p.x, p.y, v.x, v.y);
+        }
+        Some(input)
+    }
+}
+
+struct Area {
+    robots: Vec<Robot>,
+    size: IVec2,
+}
+
+impl Area {
+    fn middle(&self) -> IVec2 {
+        (self.size - IVec2::ONE) / 2
+    }
+
+    fn step(&mut self) {
+        self.robots.iter_mut().for_each(|robot| {
+            robot.p = (robot.p + robot.v).rem_euclid(self.size);
+        });
+    }
+
+    fn safety_factor(&self) -> usize {
+        let (mut a, mut b, mut c, mut d) = (0, 0, 0, 0);
+        let middle = self.middle();
+        for robot in &self.robots {
+            match (robot.p.x.cmp(&middle.x), robot.p.y.cmp(&middle.y)) {
+                (Ordering::Equal, _) => continue,
+                (_, Ordering::Equal) => continue,
+                (Ordering::Less, Ordering::Less) => a += 1,
+                (Ordering::Greater, Ordering::Less) => b += 1,
+                (Ordering::Less, Ordering::Greater) => c += 1,
+                (Ordering::Greater, Ordering::Greater) => d += 1,
+            }
+        }
+
+        a * b * c * d
+    }
+}
+
+impl fmt::Display for Area {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let robots: HashSet<_> = self.robots.iter().map(|robot| robot.p).collect();
+
+        for y in 0..self.size.y {
+            for x in 0..self.size.x {
+                let robot = robots.contains(&IVec2::new(x, y));
+                let c = if robot { '#' } else { ' ' };
+                write!(f, "{c}")?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+pub struct Robot {
+    p: IVec2,
+    v: IVec2,
+}
+
+impl Robot {
+    /// The position after some seconds, at most as many as there are tiles.
+    fn after(&self, seconds: i32, size: IVec2) -> IVec2 {
+        (self.p.rem_euclid(size) + self.v.rem_euclid(size) * seconds).rem_euclid(size)
+    }
+}
+
+/// The length of the longest run of neighboring columns that are taken.
+fn longest_run(columns: impl Iterator<Item = i32>, width: i32) -> usize {
+    let mut taken = vec![false; width as usize];
+    for x in columns {
+        taken[x as usize] = true;
+    }
+    taken
+        .split(|&taken| !taken)
+        .map(|run| run.len())
+        .max()
+        .unwrap_or(0)
+}
+
+fn parse_line(line: &str) -> Result<Robot, ParseError> {
+    let line = parse::strip_prefix(line, "p=")?;
+    let (p, v) = parse::split_once(line, " v=")?;
+    let p = parse_vec(p)?;
+    let v = parse_vec(v)?;
+    Ok(Robot { p, v })
+}
+
+fn parse_vec(s: &str) -> Result<IVec2, ParseError> {
+    let (x, y) = parse::split_once(s, ",")?;
+    Ok(IVec2::new(parse::number(x)?, parse::number(y)?))
+}
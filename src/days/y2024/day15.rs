@@ -0,0 +1,195 @@
+use crate::random::Rng;
+use crate::*;
+use glam::IVec2;
+use itertools::Itertools;
+use std::fmt::Display;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Parsed<'a> = (Warehouse, Warehouse, Vec<Direction>);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let (warehouse, directions) = parse::split_once(input, "\n\n")?;
+        let wide = Warehouse::parse(&expand_warehouse(warehouse)?)?;
+        let warehouse = Warehouse::parse(warehouse)?;
+        let mut parsed = Vec::new();
+        for line in directions.lines() {
+            parsed.extend(parse::chars(line, |c| Direction::try_from(c).ok())?);
+        }
+        Ok((warehouse, wide, parsed))
+    }
+
+    fn part1((warehouse, _, directions): &Self::Parsed<'_>) -> impl Display {
+        let mut warehouse = warehouse.clone();
+        warehouse.process(directions);
+        warehouse.gps()
+    }
+
+    fn part2((_, wide, directions): &Self::Parsed<'_>) -> impl Display {
+        let mut warehouse = wide.clone();
+        warehouse.process(directions);
+        warehouse.gps()
+    }
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(Part::One, "15-test", "10092"),
+        Example::new(Part::Two, "15-test", "9021"),
+    ];
+
+    /// A warehouse of `size` by `size` tiles full of boxes and some walls, and `10 * size` moves.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let size = IVec2::splat(size.clamp(3, 1000) as i32);
+        let mut warehouse = Grid::new(size, '#');
+        for pos in warehouse.positions().collect_vec() {
+            let inside = pos.cmpgt(IVec2::ZERO).all() && pos.cmplt(size - 1).all();
+            if inside {
+                warehouse[pos] = match rng.range(0..=19) {
+                    0 => '#',
+                    1..=6 => 'O',
+                    _ => '.',
+                };
+            }
+        }
+        let robot = IVec2::new(rng.range(1..=size.x - 2), rng.range(1..=size.y - 2));
+        warehouse[robot] = '@';
+
+        let mut input = warehouse.to_string();
+        for i in 0..size.x * 10 {
+            if i % 70 == 0 {
+                input.push('\n');
+            }
+            input.push(*rng.choose(&['^', 'v', '<', '>']));
+        }
+        input.push('\n');
+        Some(input)
+    }
+}
+
+fn expand_warehouse(warehouse: &str) -> Result<String, ParseError> {
+    let expanded = parse::chars(warehouse, |c| match c {
+        '#' => Some("##"),
+        'O' => Some("[]"),
+        '.' => Some(".."),
+        '@' => Some("@."),
+        '\n' => Some("\n"),
+        _ => None,
+    })?;
+    Ok(expanded.concat())
+}
+
+#[derive(Clone)]
+enum Tile {
+    Empty,
+    Wall,
+    Box,
+    BoxL,
+    BoxR,
+}
+
+#[derive(Clone)]
+pub struct Warehouse {
+    tiles: Grid<Tile>,
+    robot: IVec2,
+}
+
+impl Warehouse {
+    fn process(&mut self, directions: &[Direction]) {
+        for &dir in directions {
+            self.do_move(dir);
+        }
+    }
+
+    fn do_move(&mut self, dir: Direction) {
+        if !self.can_move(self.robot, dir) {
+            return;
+        }
+
+        self.robot += dir.vec();
+        self.push_boxes(self.robot, dir);
+    }
+
+    fn push_boxes(&mut self, at: IVec2, dir: Direction) {
+        match self.tiles[at] {
+            Tile::Box => {
+                self.push_boxes(at + dir.vec(), dir);
+                self.tiles[at] = Tile::Empty;
+                self.tiles[at + dir.vec()] = Tile::Box;
+            }
+            Tile::BoxL => {
+                let r = at + IVec2::X;
+                self.push_boxes(at + dir.vec(), dir);
+                if dir.is_vertical() {
+                    self.push_boxes(r + dir.vec(), dir);
+                }
+                self.tiles[at] = Tile::Empty;
+                if dir.is_vertical() {
+                    self.tiles[r] = Tile::Empty;
+                }
+                self.tiles[at + dir.vec()] = Tile::BoxL;
+                if dir.is_vertical() {
+                    self.tiles[r + dir.vec()] = Tile::BoxR;
+                }
+            }
+            Tile::BoxR => {
+                let l = at - IVec2::X;
+                self.push_boxes(at + dir.vec(), dir);
+                if dir.is_vertical() {
+                    self.push_boxes(l + dir.vec(), dir);
+                }
+                self.tiles[at] = Tile::Empty;
+                if dir.is_vertical() {
+                    self.tiles[l] = Tile::Empty;
+                }
+                self.tiles[at + dir.vec()] = Tile::BoxR;
+                if dir.is_vertical() {
+                    self.tiles[l + dir.vec()] = Tile::BoxL;
+                }
+            }
+            Tile::Empty | Tile::Wall => (),
+        }
+    }
+
+    fn can_move(&self, from: IVec2, dir: Direction) -> bool {
+        let next = from + dir.vec();
+        match self.tiles[next] {
+            Tile::Empty => true,
+            Tile::Wall => false,
+            Tile::BoxL if dir.is_vertical() => {
+                self.can_move(next, dir) && self.can_move(next + IVec2::X, dir)
+            }
+            Tile::BoxR if dir.is_vertical() => {
+                self.can_move(next, dir) && self.can_move(next - IVec2::X, dir)
+            }
+            Tile::Box | Tile::BoxL | Tile::BoxR => self.can_move(next, dir),
+        }
+    }
+
+    fn gps(&self) -> i32 {
+        self.tiles
+            .iter()
+            .map(|(p, t)| match t {
+                Tile::Box | Tile::BoxL => p.x + p.y * 100,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let tiles = Grid::try_parse(input, |c| "@.#O[]".contains(c).then_some(c))?;
+        let robot = tiles
+            .find(|&c| c == '@')
+            .ok_or_else(|| ParseError::end_of(input, "missing robot '@'"))?;
+
+        let tiles = tiles.map(|&c| match c {
+            '@' | '.' => Tile::Empty,
+            '#' => Tile::Wall,
+            'O' => Tile::Box,
+            '[' => Tile::BoxL,
+            ']' => Tile::BoxR,
+            other => unreachable!("unexpected tile '{other}'"),
+        });
+
+        Ok(Self { tiles, robot })
+    }
+}
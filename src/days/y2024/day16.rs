@@ -0,0 +1,109 @@
+use crate::random::{self, Rng};
+use crate::search::{self, ShortestPaths};
+use crate::*;
+use glam::IVec2;
+use itertools::Itertools;
+use std::fmt::Display;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Parsed<'a> = Maze;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Maze::parse(input)
+    }
+
+    fn part1(maze: &Self::Parsed<'_>) -> impl Display {
+        maze.lowest_score(&maze.dijkstra())
+    }
+
+    fn part2(maze: &Self::Parsed<'_>) -> impl Display {
+        maze.good_seats()
+    }
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(Part::One, "16-test", "7036"),
+        Example::new(Part::Two, "16-test", "45"),
+    ];
+
+    /// A maze of about `size` by `size` tiles from the bottom left to the top right corner,
+    /// with some walls removed so that there are several paths.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let cells = size.clamp(5, 1001) as i32 / 2;
+        let mut open = random::maze(rng, IVec2::splat(cells));
+        let size = open.size();
+        for pos in open.positions().collect_vec() {
+            let inside = pos.cmpgt(IVec2::ZERO).all() && pos.cmplt(size - 1).all();
+            if inside && rng.chance(0.05) {
+                open[pos] = true;
+            }
+        }
+        let mut maze = open.map(|&open| if open { '.' } else { '#' });
+        maze[IVec2::new(1, size.y - 2)] = 'S';
+        maze[IVec2::new(size.x - 2, 1)] = 'E';
+        Some(maze.to_string())
+    }
+}
+
+type State = (IVec2, Direction);
+
+pub struct Maze {
+    start: IVec2,
+    end: IVec2,
+    open: Grid<bool>,
+}
+
+impl Maze {
+    fn good_seats(&self) -> usize {
+        let paths = self.dijkstra();
+        let lowest = self.lowest_score(&paths);
+        let endings = Direction::ALL
+            .into_iter()
+            .map(|dir| (self.end, dir))
+            .filter(|end| paths.distance(end) == Some(lowest));
+        paths
+            .on_shortest_paths(endings)
+            .into_iter()
+            .map(|(pos, _)| pos)
+            .unique()
+            .count()
+    }
+
+    fn lowest_score(&self, paths: &ShortestPaths<State, usize>) -> usize {
+        Direction::ALL
+            .into_iter()
+            .filter_map(|dir| paths.distance(&(self.end, dir)))
+            .min()
+            .expect("parse checked that the end can be reached")
+    }
+
+    fn dijkstra(&self) -> ShortestPaths<State, usize> {
+        search::dijkstra((self.start, Direction::East), |&(pos, dir)| {
+            let forward = pos + dir.vec();
+            let forward = (self.open.get(forward) == Some(&true)).then_some(((forward, dir), 1));
+            let turns = [
+                ((pos, dir.rotate_cw()), 1000),
+                ((pos, dir.rotate_ccw()), 1000),
+            ];
+            forward.into_iter().chain(turns)
+        })
+    }
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let tiles = Grid::try_parse(input, |c| "SE.#".contains(c).then_some(c))?;
+        let start = tiles
+            .find(|&c| c == 'S')
+            .ok_or_else(|| ParseError::end_of(input, "missing start 'S'"))?;
+        let end = tiles
+            .find(|&c| c == 'E')
+            .ok_or_else(|| ParseError::end_of(input, "missing end 'E'"))?;
+        let open = tiles.map(|&c| c != '#');
+        if !regions::flood_fill(&open, start, |&open| open)[end] {
+            let end = &input[input.find('E').unwrap()..][..1];
+            return Err(ParseError::new(end, "cannot be reached from the start 'S'"));
+        }
+
+        Ok(Self { start, end, open })
+    }
+}
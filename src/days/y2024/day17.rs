@@ -0,0 +1,269 @@
+mod reference;
+
+use crate::parallel::{self, prelude::*};
+use crate::parse::{self, ParseError};
+use crate::random::Rng;
+use crate::{Example, Part, Solution};
+use itertools::Itertools;
+use std::cmp::Reverse;
+use std::fmt::Display;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Parsed<'a> = Computer;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Computer::parse(input)
+    }
+
+    fn part1(computer: &Self::Parsed<'_>) -> impl Display {
+        let mut computer = computer.clone();
+        computer.run();
+        computer.output.iter().map(usize::to_string).join(",")
+    }
+
+    fn part2(computer: &Self::Parsed<'_>) -> impl Display {
+        // Idea: Test all possible combinations of bits in this mask, then move the mask over init_a.
+        let mutate_bits = 16;
+        let mutate_to = 2usize.pow(mutate_bits);
+        let mut init_a = 0;
+
+        // Do a few iterations to be safe
+        for _ in 0..3 {
+            // Shuffle bits around to improve
+            for shift in (0..(usize::BITS - mutate_bits)).rev() {
+                let mask = !((mutate_to - 1) << shift);
+                let base = init_a & mask;
+                // Keep the smallest add among the best ones, no matter in which order they are tried.
+                let (_, Reverse(best_add)) = parallel::iter(0..mutate_to)
+                    .map(|add| {
+                        let mut computer = computer.clone();
+                        computer.a = base | (add << shift);
+                        computer.run();
+                        (computer.quineness(), Reverse(add))
+                    })
+                    .max()
+                    .unwrap();
+                init_a = base | (best_add << shift);
+            }
+        }
+
+        init_a
+    }
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(Part::One, "17-test", "5,7,3,0"),
+        Example::new(Part::Two, "17-test", "117440"),
+    ];
+
+    /// Part 1 is a plain simulation.
+    fn reference(computer: &Self::Parsed<'_>, part: Part) -> Option<String> {
+        match part {
+            Part::One => Some(reference::part1(computer)),
+            Part::Two => None,
+        }
+    }
+
+    /// A program like the real ones, which outputs one number per loop and shifts register A by 3 bits,
+    /// with random constants for which a copy of the program exists. `size` is the number of bits of register A.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        loop {
+            let mut program = vec![2, 4, 1, rng.range(0..=7), 7, 5];
+            let mut mix = [
+                vec![1, rng.range(0..=7)],
+                vec![4, rng.range(0..=7)],
+                vec![0, 3],
+            ];
+            rng.shuffle(&mut mix);
+            program.extend(mix.concat());
+            program.extend([5, 5, 3, 0]);
+
+            let bits = size.clamp(1, 63);
+            let computer = Computer {
+                a: rng.range(1..=(1 << bits) - 1),
+                b: 0,
+                c: 0,
+                program,
+                ip: 0,
+                output: Vec::new(),
+            };
+            if lowest_quine(&computer).is_some() {
+                return Some(format!(
+                    "Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+                    computer.a,
+                    computer.program.iter().join(",")
+                ));
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Computer {
+    a: usize,
+    b: usize,
+    c: usize,
+    program: Vec<usize>,
+    ip: usize,
+    output: Vec<usize>,
+}
+
+impl Computer {
+    fn run(&mut self) {
+        while !self.is_halted() {
+            self.step();
+        }
+    }
+
+    fn quineness(&self) -> usize {
+        self.output
+            .iter()
+            .zip(&self.program)
+            .filter(|(a, b)| a == b)
+            .count()
+    }
+
+    /// Run the next instruction. Cannot fail, because [`Computer::parse`] only accepts valid programs.
+    fn step(&mut self) {
+        match self.opcode() {
+            0 => {
+                self.a = self.divide_a();
+                self.ip += 2;
+            }
+            1 => {
+                self.b ^= self.literal_operand();
+                self.ip += 2;
+            }
+            2 => {
+                self.b = self.combo_operand() % 8;
+                self.ip += 2;
+            }
+            3 => {
+                if self.a == 0 {
+                    self.ip += 2;
+                } else {
+                    self.ip = self.literal_operand();
+                }
+            }
+            4 => {
+                self.b ^= self.c;
+                self.ip += 2;
+            }
+            5 => {
+                self.output.push(self.combo_operand() % 8);
+                self.ip += 2;
+            }
+            6 => {
+                self.b = self.divide_a();
+                self.ip += 2;
+            }
+            _ => {
+                self.c = self.divide_a();
+                self.ip += 2;
+            }
+        }
+    }
+
+    /// Register A divided by 2 to the power of the combo operand, which is 0 for powers beyond `usize`.
+    fn divide_a(&self) -> usize {
+        u32::try_from(self.combo_operand())
+            .ok()
+            .and_then(|exp| self.a.checked_shr(exp))
+            .unwrap_or(0)
+    }
+
+    fn is_halted(&self) -> bool {
+        self.ip >= self.program.len()
+    }
+
+    fn opcode(&self) -> usize {
+        self.program[self.ip]
+    }
+
+    fn literal_operand(&self) -> usize {
+        self.program[self.ip + 1]
+    }
+
+    fn combo_operand(&self) -> usize {
+        match self.literal_operand() {
+            n @ 0..=3 => n,
+            4 => self.a,
+            5 => self.b,
+            _ => self.c,
+        }
+    }
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (registers, program) = parse::split_once(input, "\n\n")?;
+        let mut lines = registers.lines();
+        let mut register = |prefix| -> Result<usize, ParseError> {
+            let line = lines
+                .next()
+                .ok_or_else(|| ParseError::end_of(registers, "missing register"))?;
+            parse::number(parse::strip_prefix(line, prefix)?)
+        };
+        let a = register("Register A: ")?;
+        let b = register("Register B: ")?;
+        let c = register("Register C: ")?;
+        let program = parse_program(parse::strip_prefix(program.trim_end(), "Program: ")?)?;
+        Ok(Self {
+            a,
+            b,
+            c,
+            program,
+            ip: 0,
+            output: Vec::new(),
+        })
+    }
+}
+
+/// Parse a program that [`Computer::step`] can run: pairs of an opcode and an operand from 0 to 7,
+/// where combo operands are not 7 and jumps go to opcodes.
+fn parse_program(s: &str) -> Result<Vec<usize>, ParseError> {
+    let values: Vec<&str> = s.split(',').collect();
+    let mut program = Vec::with_capacity(values.len());
+    for instruction in values.chunks(2) {
+        let [opcode, operand] = *instruction else {
+            return Err(ParseError::end_of(s, "missing operand"));
+        };
+        let [opcode, operand] = [opcode, operand].map(|value| {
+            parse::number(value).and_then(|number: usize| match number {
+                0..=7 => Ok((value, number)),
+                _ => Err(ParseError::new(value, "expected a value from 0 to 7")),
+            })
+        });
+        let ((_, opcode), (operand_text, operand)) = (opcode?, operand?);
+        if matches!(opcode, 0 | 2 | 5 | 6 | 7) && operand == 7 {
+            return Err(ParseError::new(operand_text, "invalid combo operand"));
+        }
+        if opcode == 3 && operand % 2 == 1 {
+            return Err(ParseError::new(operand_text, "jump to an operand"));
+        }
+        program.extend([opcode, operand]);
+    }
+    Ok(program)
+}
+
+/// The lowest value of register A for which the program outputs itself, built up from the highest 3 bits.
+///
+/// Only works for programs that output one number per loop and shift A by 3 bits, like all real inputs.
+fn lowest_quine(computer: &Computer) -> Option<usize> {
+    fn extend(computer: &Computer, a: usize, matched: usize) -> Option<usize> {
+        let program = &computer.program;
+        if matched == program.len() {
+            return Some(a);
+        }
+        let expected = &program[program.len() - matched - 1..];
+        (0..8)
+            .map(|bits| (a << 3) | bits)
+            .filter(|&a| {
+                let mut computer = computer.clone();
+                computer.a = a;
+                computer.run();
+                a != 0 && computer.output == expected
+            })
+            .find_map(|a| extend(computer, a, matched + 1))
+    }
+    extend(computer, 0, 0)
+}
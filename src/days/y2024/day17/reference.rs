@@ -0,0 +1,45 @@
+//! Slow but obviously correct solutions, see [`Solution::reference`](crate::Solution::reference).
+//!
+//! Part 2 has none: the copies of real-sized programs are found at values of register A of about
+//! 48 bits, far too many to try one by one.
+
+use super::Computer;
+use itertools::Itertools;
+
+/// Run the program with a separate emulator that spells out every instruction.
+pub fn part1(computer: &Computer) -> String {
+    let program = &computer.program;
+    let (mut a, mut b, mut c) = (computer.a, computer.b, computer.c);
+    let mut ip = 0;
+    let mut output = Vec::new();
+    while ip < program.len() {
+        let (opcode, operand) = (program[ip], program[ip + 1]);
+        let combo = match operand {
+            4 => a,
+            5 => b,
+            6 => c,
+            _ => operand,
+        };
+        ip += 2;
+        match opcode {
+            0 => a = divide(a, combo),
+            1 => b ^= operand,
+            2 => b = combo % 8,
+            3 if a != 0 => ip = operand,
+            3 => {}
+            4 => b ^= c,
+            5 => output.push(combo % 8),
+            6 => b = divide(a, combo),
+            _ => c = divide(a, combo),
+        }
+    }
+    output.iter().join(",")
+}
+
+/// `a` divided by 2 to the power of `exp`.
+fn divide(a: usize, exp: usize) -> usize {
+    u32::try_from(exp)
+        .ok()
+        .and_then(|exp| 2usize.checked_pow(exp))
+        .map_or(0, |divisor| a / divisor)
+}
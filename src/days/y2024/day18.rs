@@ -0,0 +1,113 @@
+use crate::random::Rng;
+use crate::*;
+use glam::IVec2;
+use itertools::Itertools;
+use std::fmt::Display;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Parsed<'a> = Vec<IVec2>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        input.lines().map(parse_line).collect()
+    }
+
+    fn part1(blocks: &Self::Parsed<'_>) -> impl Display {
+        let (size, steps) = dimensions(blocks);
+        simulate(size, steps, blocks).unwrap()
+    }
+
+    fn part2(blocks: &Self::Parsed<'_>) -> impl Display {
+        let (size, _) = dimensions(blocks);
+        let mut steps = 1;
+        let mut div = blocks.len() / 2;
+
+        // Binary search for the number of steps
+        loop {
+            let a_works = simulate(size, steps, blocks).is_some();
+            let b_works = simulate(size, steps + 1, blocks).is_some();
+            match (a_works, b_works) {
+                (true, false) => break,
+                (true, true) => {
+                    steps += div;
+                    div = 1.max(div / 2);
+                }
+                (false, false) => {
+                    steps -= div;
+                    div = 1.max(div / 2);
+                }
+                (false, true) => unreachable!(),
+            }
+        }
+
+        let blocker = blocks[steps];
+        format!("{},{}", blocker.x, blocker.y)
+    }
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(Part::One, "18-test", "22"),
+        Example::new(Part::Two, "18-test", "6,1"),
+    ];
+
+    /// All bytes of a memory space that is 7 by 7 if `size` is at most 6 and 71 by 71 otherwise,
+    /// in an order where the exit can still be reached after the bytes of part 1.
+    /// The input ends at a random point after the byte that cuts off the exit.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let (size, steps) = if size <= 6 { (6, 12) } else { (70, 1024) };
+        let mut bytes = (0..=size)
+            .cartesian_product(0..=size)
+            .map(|(x, y)| IVec2::new(x, y))
+            .filter(|&byte| byte != IVec2::ZERO && byte != IVec2::splat(size))
+            .collect_vec();
+        loop {
+            rng.shuffle(&mut bytes);
+            let counts = (0..=bytes.len()).collect_vec();
+            let cut_off = counts.partition_point(|&n| simulate(size, n, &bytes).is_some());
+            if cut_off > steps {
+                let len = rng.range(cut_off..=bytes.len());
+                let lines = bytes[..len]
+                    .iter()
+                    .map(|byte| format!("{},{}\n", byte.x, byte.y));
+                return Some(lines.collect());
+            }
+        }
+    }
+}
+
+/// Return the size of the memory space and the number of bytes that fall in part 1.
+/// The example is a lot smaller than the real input.
+fn dimensions(blocks: &[IVec2]) -> (i32, usize) {
+    let largest = blocks.iter().map(|block| block.max_element()).max();
+    if largest.is_some_and(|largest| largest > 6) {
+        (70, 1024)
+    } else {
+        (6, 12)
+    }
+}
+
+/// Return the length of the shortest path to the exit, if there is one.
+fn simulate(size: i32, steps: usize, blocks: &[IVec2]) -> Option<usize> {
+    let mut walls = Grid::new(IVec2::splat(size + 1), false);
+    for &block in blocks.iter().take(steps) {
+        walls[block] = true;
+    }
+
+    let paths = search::bfs(IVec2::ZERO, |&pos| {
+        walls.neighbors4(pos).filter(|&next| !walls[next])
+    });
+    paths.distance(&IVec2::splat(size))
+}
+
+fn parse_line(line: &str) -> Result<IVec2, ParseError> {
+    let (x, y) = parse::split_once(line, ",")?;
+    let x = parse::number(x)?;
+    let y = parse::number(y)?;
+    if !(0..=70).contains(&x) || !(0..=70).contains(&y) {
+        return Err(ParseError::new(
+            line,
+            "coordinates must be between 0 and 70",
+        ));
+    }
+    Ok(IVec2::new(x, y))
+}
@@ -0,0 +1,90 @@
+use crate::random::Rng;
+use crate::*;
+use cached::proc_macro::cached;
+use cached::UnboundCache;
+use std::fmt::Display;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Parsed<'a> = (Vec<&'a str>, Vec<&'a str>);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let (available, requested) = parse::split_once(input, "\n\n")?;
+        let available = available.split(", ").collect();
+        let requested = requested.lines().collect();
+        Ok((available, requested))
+    }
+
+    fn part1((available, requested): &Self::Parsed<'_>) -> impl Display {
+        requested
+            .iter()
+            .filter(|requested| combinations(requested, available) > 0)
+            .count()
+    }
+
+    fn part2((available, requested): &Self::Parsed<'_>) -> impl Display {
+        requested
+            .iter()
+            .map(|requested| combinations(requested, available))
+            .sum::<usize>()
+    }
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(Part::One, "19-test", "6"),
+        Example::new(Part::Two, "19-test", "16"),
+    ];
+
+    /// `size` towel patterns and `size` designs. Half of the designs are made of patterns, the others are random.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        const COLORS: [char; 5] = ['w', 'u', 'b', 'r', 'g'];
+        let random = |rng: &mut Rng, len: usize| -> String {
+            (0..len).map(|_| *rng.choose(&COLORS)).collect()
+        };
+
+        // Without a pattern of every single color, some designs are impossible.
+        let missing = rng.choose(&COLORS).to_string();
+        let mut patterns = Vec::new();
+        while patterns.len() < size.clamp(1, 1000) {
+            let len = rng.range(1..=8);
+            let pattern = random(rng, len);
+            if pattern != missing && !patterns.contains(&pattern) {
+                patterns.push(pattern);
+            }
+        }
+
+        let mut input = patterns.join(", ") + "\n\n";
+        for _ in 0..size.max(1) {
+            let len = rng.range(20..=60);
+            let design = if rng.chance(0.5) {
+                let mut design = String::new();
+                while design.len() < len {
+                    design += rng.choose(&patterns).as_str();
+                }
+                design
+            } else {
+                random(rng, len)
+            };
+            input += &design;
+            input.push('\n');
+        }
+        Some(input)
+    }
+}
+
+#[cached(
+    type = "UnboundCache<(String, usize), usize>",
+    create = "{ UnboundCache::new() }",
+    convert = r#"{ (requested.to_string(), available.len()) }"#
+)]
+fn combinations(requested: &str, available: &[&str]) -> usize {
+    if requested.is_empty() {
+        return 1;
+    }
+
+    available
+        .iter()
+        .flat_map(|available| requested.strip_prefix(available))
+        .map(|requested| combinations(requested, available))
+        .sum()
+}
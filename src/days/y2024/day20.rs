@@ -0,0 +1,104 @@
+use crate::parallel::{self, prelude::*};
+use crate::random::{self, Rng};
+use crate::*;
+use glam::IVec2;
+use std::fmt::Display;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Parsed<'a> = Maze;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Maze::parse(input)
+    }
+
+    fn part1(maze: &Self::Parsed<'_>) -> impl Display {
+        count_cheats(maze, 2)
+    }
+
+    fn part2(maze: &Self::Parsed<'_>) -> impl Display {
+        count_cheats(maze, 20)
+    }
+
+    /// A single track through a maze of about `size` by `size` tiles.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let cells = size.clamp(5, 1001) as i32 / 2;
+        let open = random::maze(rng, IVec2::splat(cells));
+        let (mut start, mut end) = (IVec2::ONE, open.size() - IVec2::splat(2));
+        if rng.chance(0.5) {
+            (start, end) = (end, start);
+        }
+        let paths = search::bfs(start, |&pos| {
+            open.neighbors4(pos).filter(|&next| open[next])
+        });
+
+        let mut track = Grid::new(open.size(), '#');
+        for pos in paths.path(&end)? {
+            track[pos] = '.';
+        }
+        track[start] = 'S';
+        track[end] = 'E';
+        Some(track.to_string())
+    }
+}
+
+/// Count the cheats that save at least 100 picoseconds.
+fn count_cheats(maze: &Maze, max_cheat: u32) -> usize {
+    let path = maze.path();
+    parallel::iter(0..(path.len() - 1))
+        .map(|from_i| {
+            let from = path[from_i];
+            ((from_i + 1)..path.len())
+                .filter(|&to_i| {
+                    let distance = distance(from, path[to_i]);
+                    // A distance of 1 is not really a cheat
+                    if distance > max_cheat || distance == 1 {
+                        return false;
+                    }
+                    let saved = to_i - from_i - distance as usize;
+                    saved >= 100
+                })
+                .count()
+        })
+        .sum()
+}
+
+fn distance(a: IVec2, b: IVec2) -> u32 {
+    a.x.abs_diff(b.x) + a.y.abs_diff(b.y)
+}
+
+#[derive(Clone)]
+pub struct Maze {
+    start: IVec2,
+    end: IVec2,
+    open: Grid<bool>,
+}
+
+impl Maze {
+    fn path(&self) -> Vec<IVec2> {
+        let paths = search::bfs(self.start, |&pos| {
+            self.open.neighbors4(pos).filter(|&next| self.open[next])
+        });
+        paths
+            .path(&self.end)
+            .expect("parse checked that the end can be reached")
+    }
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let tiles = Grid::try_parse(input, |c| "SE.#".contains(c).then_some(c))?;
+        let start = tiles
+            .find(|&c| c == 'S')
+            .ok_or_else(|| ParseError::end_of(input, "missing start 'S'"))?;
+        let end = tiles
+            .find(|&c| c == 'E')
+            .ok_or_else(|| ParseError::end_of(input, "missing end 'E'"))?;
+        let open = tiles.map(|&c| c != '#');
+        if !regions::flood_fill(&open, start, |&open| open)[end] {
+            let end = &input[input.find('E').unwrap()..][..1];
+            return Err(ParseError::new(end, "cannot be reached from the start 'S'"));
+        }
+
+        Ok(Self { start, end, open })
+    }
+}
@@ -1,37 +1,56 @@
-use aoc::*;
+use crate::random::Rng;
+use crate::*;
 use cached::proc_macro::cached;
 use itertools::Itertools;
-use std::collections::VecDeque;
+use std::fmt::Display;
 use std::iter;
 
-const INPUT: &str = include_str!("../../input/21");
+pub struct Day21;
 
-fn main() {
-    assert_example!(part1, "21-test", 126384);
-    println!("Part 1: {}", part1(INPUT));
-    println!("Part 2: {}", part2(INPUT));
-}
+impl Solution for Day21 {
+    type Parsed<'a> = Vec<(&'a str, usize)>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        input.lines().map(parse_code).collect()
+    }
 
-fn part1(input: &str) -> usize {
-    let pads = vec![Pad::Num, Pad::Dir, Pad::Dir];
-    input
-        .lines()
-        .map(|code| complexity(code, pads.clone()))
-        .sum()
+    fn part1(codes: &Self::Parsed<'_>) -> impl Display {
+        let pads = vec![Pad::Num, Pad::Dir, Pad::Dir];
+        codes
+            .iter()
+            .map(|&code| complexity(code, pads.clone()))
+            .sum::<usize>()
+    }
+
+    fn part2(codes: &Self::Parsed<'_>) -> impl Display {
+        let robots = iter::repeat_n(Pad::Dir, 25);
+        let pads = iter::once(Pad::Num).chain(robots).collect_vec();
+        codes
+            .iter()
+            .map(|&code| complexity(code, pads.clone()))
+            .sum::<usize>()
+    }
+
+    const EXAMPLES: &'static [Example] = &[Example::new(Part::One, "21-test", "126384")];
+
+    /// `size` codes of three digits.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let codes = (0..size.max(1)).map(|_| format!("{:03}A\n", rng.range(0..=999)));
+        Some(codes.collect())
+    }
 }
 
-fn part2(input: &str) -> usize {
-    let robots = iter::repeat(Pad::Dir).take(25);
-    let pads = iter::once(Pad::Num).chain(robots).collect_vec();
-    input
-        .lines()
-        .map(|code| complexity(code, pads.clone()))
-        .sum()
+/// Return the code and its numeric part.
+fn parse_code(code: &str) -> Result<(&str, usize), ParseError> {
+    let digits = code
+        .strip_suffix('A')
+        .ok_or_else(|| ParseError::new(code, "expected code ending in 'A'"))?;
+    parse::chars(digits, |c| c.is_ascii_digit().then_some(c))?;
+    Ok((code, parse::number(digits)?))
 }
 
-fn complexity(code: &str, pads: Vec<Pad>) -> usize {
+fn complexity((code, num): (&str, usize), pads: Vec<Pad>) -> usize {
     let sequence = expand(code.to_string(), pads);
-    let num: usize = code[0..3].parse().unwrap();
     sequence * num
 }
 
@@ -64,46 +83,27 @@ fn expand(input: String, mut pads: Vec<Pad>) -> usize {
     output
 }
 
+/// Return all shortest paths between two keys as a list of (key, direction) moves.
 #[cached]
 fn shortest_paths(start: char, end: char, pad: Pad) -> Vec<Vec<(char, char)>> {
-    if start == end {
-        return vec![vec![]];
-    }
-
-    let next_chars = pad.next_moves();
-
-    let initial_work = next_chars(start).iter().map(|&w| vec![w]);
-    let mut work: VecDeque<Vec<(char, char)>> = VecDeque::from_iter(initial_work);
-
-    let mut shortest_paths = Vec::new();
-    let mut shortest_length = usize::MAX;
-
-    while let Some(path) = work.pop_front() {
-        if path.len() > shortest_length {
-            // BFS can only get worse now
-            break;
-        }
-
-        let head = path.last().copied().unwrap();
-
-        if head.0 == end {
-            shortest_length = path.len();
-            shortest_paths.push(path);
-            continue;
-        }
-
-        for &next in next_chars(head.0) {
-            if path.iter().any(|&(c, _)| c == next.0) {
-                // Already been there
-                continue;
-            }
-            let mut path = path.clone();
-            path.push(next);
-            work.push_back(path);
-        }
-    }
-
-    shortest_paths
+    let next_moves = pad.next_moves();
+    let paths = search::bfs(start, |&key| next_moves(key).iter().map(|&(next, _)| next));
+
+    paths
+        .all_paths(&end)
+        .into_iter()
+        .map(|keys| {
+            keys.into_iter()
+                .tuple_windows()
+                .map(|(from, to)| {
+                    *next_moves(from)
+                        .iter()
+                        .find(|&&(next, _)| next == to)
+                        .unwrap()
+                })
+                .collect()
+        })
+        .collect()
 }
 
 type Moves = &'static [(char, char)];
@@ -0,0 +1,104 @@
+use crate::parallel;
+use crate::random::Rng;
+use crate::*;
+use itertools::Itertools;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::iter;
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Parsed<'a> = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        input.lines().map(parse::number).collect()
+    }
+
+    fn part1(buyers: &Self::Parsed<'_>) -> impl Display {
+        buyers
+            .iter()
+            .map(|&secret| secret_numbers(secret).last().unwrap())
+            .sum::<usize>()
+    }
+
+    fn part2(buyers: &Self::Parsed<'_>) -> impl Display {
+        let sequences = parallel::fold(
+            buyers,
+            HashMap::new,
+            |mut sequences, &secret| {
+                for (seq, price) in possible_sales(secret) {
+                    *sequences.entry(seq).or_insert(0) += price as usize;
+                }
+                sequences
+            },
+            |mut a, b| {
+                for (seq, bananas) in b {
+                    *a.entry(seq).or_insert(0) += bananas;
+                }
+                a
+            },
+        );
+
+        // Without buyers, no bananas are sold.
+        sequences.into_values().max().unwrap_or(0)
+    }
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(Part::One, "22-test", "37327623"),
+        Example::new(Part::Two, "22-test", "24"),
+    ];
+
+    /// `size` initial secret numbers.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let secrets = (0..size.max(1)).map(|_| format!("{}\n", rng.range(1..=16777215)));
+        Some(secrets.collect())
+    }
+}
+
+fn possible_sales(secret: usize) -> HashMap<[i8; 4], u8> {
+    let mut sales = HashMap::new();
+    for (a, b, c, d) in price_changes(secret).tuple_windows() {
+        let seq = [a.change, b.change, c.change, d.change];
+        sales.entry(seq).or_insert(d.price);
+    }
+    sales
+}
+
+fn price_changes(secret: usize) -> impl Iterator<Item = PriceChange> {
+    let prices = secret_numbers(secret).map(|s| (s % 10) as u8);
+    prices.tuple_windows().map(|(a, b)| PriceChange {
+        price: b,
+        change: b as i8 - a as i8,
+    })
+}
+
+#[derive(Debug, Copy, Clone)]
+struct PriceChange {
+    price: u8,
+    change: i8,
+}
+
+fn secret_numbers(mut secret: usize) -> impl Iterator<Item = usize> {
+    iter::from_fn(move || {
+        let s = secret;
+        secret = mix_prune(secret);
+        Some(s)
+    })
+    .take(2001)
+}
+
+fn mix_prune(mut secret: usize) -> usize {
+    const MOD: usize = 16777216;
+
+    let n = secret * 64;
+    secret = (secret ^ n) % MOD;
+
+    let n = secret / 32;
+    secret = (secret ^ n) % MOD;
+
+    let n = secret * 2048;
+    secret = (secret ^ n) % MOD;
+
+    secret
+}
@@ -0,0 +1,125 @@
+mod reference;
+
+use crate::graph::Graph;
+use crate::random::Rng;
+use crate::*;
+use itertools::Itertools;
+use std::cmp::{Ordering, Reverse};
+use std::fmt::{self, Display};
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Parsed<'a> = Graph<Computer>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        input.lines().map(parse_line).collect()
+    }
+
+    fn part1(graph: &Self::Parsed<'_>) -> impl Display {
+        graph
+            .triangles()
+            .into_iter()
+            .filter(|triangle| triangle.iter().any(|computer| computer.0 == 't'))
+            .count()
+    }
+
+    fn part2(graph: &Self::Parsed<'_>) -> impl Display {
+        // The password of the first of the largest groups, if there is more than one.
+        graph
+            .maximal_cliques()
+            .into_iter()
+            .map(|clique| (clique.len(), Reverse(clique.into_iter().sorted().join(","))))
+            .max()
+            .map(|(_, Reverse(password))| password)
+            .unwrap_or_default()
+    }
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(Part::One, "23-test", "7"),
+        Example::new(Part::Two, "23-test", "co,de,ka,ta"),
+    ];
+
+    fn reference(graph: &Self::Parsed<'_>, part: Part) -> Option<String> {
+        Some(match part {
+            Part::One => reference::part1(graph).to_string(),
+            Part::Two => reference::part2(graph),
+        })
+    }
+
+    /// A network of `size` computers, at most 676, with a hidden LAN party and random connections otherwise.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut names = ('a'..='z')
+            .cartesian_product('a'..='z')
+            .map(|(a, b)| format!("{a}{b}"))
+            .collect_vec();
+        rng.shuffle(&mut names);
+        names.truncate(size.clamp(4, names.len()));
+
+        // The first computers are at the party. Other computers know at most two of its guests,
+        // so they cannot take a guest's place, and connections between them are rare enough
+        // that they hardly ever form another group of the same size.
+        let party = (names.len() / 10).clamp(4, 13);
+        let chance = 2.0 / names.len() as f64;
+        let mut guests_known = vec![0; names.len()];
+        let mut connections = Vec::new();
+        for (a, b) in (0..names.len()).tuple_combinations() {
+            let connected = if b < party {
+                true
+            } else if a < party {
+                guests_known[b] < 2 && rng.chance(chance)
+            } else {
+                rng.chance(chance)
+            };
+            if connected {
+                if a < party {
+                    guests_known[b] += 1;
+                }
+                connections.push(if rng.chance(0.5) { (a, b) } else { (b, a) });
+            }
+        }
+        rng.shuffle(&mut connections);
+
+        let lines = connections
+            .into_iter()
+            .map(|(a, b)| format!("{}-{}\n", names[a], names[b]));
+        Some(lines.collect())
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Computer(char, char);
+
+impl Computer {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        match s.chars().collect_vec()[..] {
+            [a, b] => Ok(Self(a, b)),
+            _ => Err(ParseError::new(s, "computer needs 2 chars")),
+        }
+    }
+}
+
+impl Ord for Computer {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0).then(self.1.cmp(&other.1))
+    }
+}
+
+impl PartialOrd for Computer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Computer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.0, self.1)
+    }
+}
+
+fn parse_line(line: &str) -> Result<(Computer, Computer), ParseError> {
+    let (left, right) = parse::split_once(line, "-")?;
+    let left = Computer::parse(left)?;
+    let right = Computer::parse(right)?;
+    Ok((left, right))
+}
@@ -0,0 +1,59 @@
+//! Slow but obviously correct solutions, see [`Solution::reference`](crate::Solution::reference).
+
+use super::Computer;
+use crate::graph::Graph;
+use itertools::Itertools;
+
+/// Check every set of three computers.
+pub fn part1(graph: &Graph<Computer>) -> usize {
+    let computers = graph.nodes().sorted().collect_vec();
+    computers
+        .into_iter()
+        .tuple_combinations()
+        .filter(|&(a, b, c)| {
+            connected(graph, a, b) && connected(graph, b, c) && connected(graph, a, c)
+        })
+        .filter(|&(a, b, c)| [a, b, c].iter().any(|computer| computer.0 == 't'))
+        .count()
+}
+
+/// Find all groups of connected computers by growing them one computer at a time,
+/// and return the first of the largest ones.
+///
+/// Only computers that sort after all members are added, so that every group is found once.
+pub fn part2(graph: &Graph<Computer>) -> String {
+    let computers = graph.nodes().copied().sorted().collect_vec();
+    let mut groups = computers
+        .iter()
+        .map(|&computer| vec![computer])
+        .collect_vec();
+    loop {
+        let larger = groups
+            .iter()
+            .flat_map(|group| {
+                computers
+                    .iter()
+                    .filter(|&computer| computer > group.last().unwrap())
+                    .filter(|&computer| {
+                        group
+                            .iter()
+                            .all(|member| connected(graph, member, computer))
+                    })
+                    .map(|&computer| [group.as_slice(), &[computer]].concat())
+            })
+            .collect_vec();
+        if larger.is_empty() {
+            break;
+        }
+        groups = larger;
+    }
+    groups
+        .iter()
+        .map(|group| group.iter().join(","))
+        .min()
+        .unwrap_or_default()
+}
+
+fn connected(graph: &Graph<Computer>, a: &Computer, b: &Computer) -> bool {
+    graph.contains_edge(a, b)
+}
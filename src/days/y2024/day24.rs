@@ -0,0 +1,449 @@
+use crate::random::Rng;
+use crate::*;
+use itertools::Itertools;
+use std::cmp::{Ordering, Reverse};
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::{self, Display};
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Parsed<'a> = Device;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Device::parse(input)
+    }
+
+    fn part1(device: &Self::Parsed<'_>) -> impl Display {
+        let mut device = device.clone();
+        device.propagate();
+        device.integer('z')
+    }
+
+    /// Draw a diagram of the circuit with the gates that look miswired. The rules only find suspects,
+    /// so there is no answer, the swapped pairs have to be found in the diagram by hand.
+    fn part2(device: &Self::Parsed<'_>) -> impl Display {
+        let problems = device
+            .gates
+            .iter()
+            .filter_map(|&gate| Some((gate, gate_problem(device, gate)?)))
+            .collect_vec();
+
+        artifact::emit("circuit.dot", diagram(device, &problems));
+        ""
+    }
+
+    const EXAMPLES: &'static [Example] = &[Example::new(Part::One, "24-test", "2024")];
+
+    /// A ripple-carry adder of `size` bits, between 3 and 45, where four pairs of gates have their outputs swapped.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let bits = size.clamp(3, 45);
+        let gates = loop {
+            let mut gates = adder(rng, bits);
+            let mut swapped = (0..gates.len()).collect_vec();
+            rng.shuffle(&mut swapped);
+            for pair in swapped[..8].chunks(2) {
+                let (a, b) = (gates[pair[0]].out, gates[pair[1]].out);
+                gates[pair[0]].out = b;
+                gates[pair[1]].out = a;
+            }
+            if computable(&gates) {
+                break gates;
+            }
+        };
+
+        let mut input = String::new();
+        for c in ['x', 'y'] {
+            for n in 0..bits {
+                input += &format!("{}: {}\n", numbered(c, n), rng.range(0..=1));
+            }
+        }
+        input.push('\n');
+        for gate in gates {
+            let op = match gate.op {
+                Op::And => "AND",
+                Op::Or => "OR",
+                Op::Xor => "XOR",
+            };
+            input += &format!("{} {op} {} -> {}\n", gate.a, gate.b, gate.out);
+        }
+        Some(input)
+    }
+}
+
+/// Draw the circuit for graphviz, with the gates that look miswired in red.
+/// The problems are listed in comments at the top.
+fn diagram(device: &Device, problems: &[(Gate, String)]) -> String {
+    let mut dot = String::new();
+    let mut line = |line: String| {
+        dot.push_str(&line);
+        dot.push('\n');
+    };
+
+    line("// Look for mistakes in the colored nodes after rendering the diagram:".into());
+    line("//     cargo run --release -- run 24 --format json".into());
+    line("//     dot -T svg -o 24.svg target/artifacts/2024/24/circuit.dot".into());
+    for (_, problem) in problems {
+        line(format!("// {problem}"));
+    }
+
+    line("digraph {".into());
+    for gate in &device.gates {
+        let shape = match gate.op {
+            Op::And => "box",
+            Op::Or => "circle",
+            Op::Xor => "diamond",
+        };
+        let color = if problems.iter().any(|(wrong, _)| wrong.out == gate.out) {
+            ",fillcolor=red,style=filled"
+        } else {
+            ""
+        };
+        line(format!(
+            r#"{} [label={:?}{color},shape={shape}];"#,
+            gate.out, gate.op
+        ));
+        line(format!("{} -> {} [label={}];", gate.a, gate.out, gate.a));
+        line(format!("{} -> {} [label={}];", gate.b, gate.out, gate.b));
+    }
+    for gate in device.gates.iter().filter(|gate| gate.out.is_output()) {
+        line(format!(
+            "{}_out [label={},shape=doublecircle];",
+            gate.out, gate.out
+        ));
+        line(format!(
+            "{} -> {}_out [label={}];",
+            gate.out, gate.out, gate.out
+        ));
+    }
+    line("}".into());
+    dot
+}
+
+/// Explain what is wrong with a gate, or return `None` if it seems to be connected correctly.
+/// Rules were derived by staring at circuit diagrams:
+/// https://en.wikipedia.org/wiki/Adder_(electronics)
+fn gate_problem(device: &Device, gate: Gate) -> Option<String> {
+    match gate.op {
+        Op::And | Op::Xor => {
+            if gate.out.is_output() && gate.out.n().is_some_and(|n| n <= 1) {
+                return None;
+            }
+            if gate.a.is_input() && gate.b.is_input() {
+                return None;
+            }
+            let a = device.gate_with_output(gate.a);
+            let b = device.gate_with_output(gate.b);
+            let (Some(a), Some(b)) = (a, b) else {
+                return Some(format!(
+                    "[{:?}] gate is missing an input: {gate:?}",
+                    gate.op
+                ));
+            };
+            if a.op == Op::Or && b.op == Op::Xor || a.op == Op::Xor && b.op == Op::Or {
+                return None;
+            }
+            Some(format!(
+                "[{:?}] needs to be connected to input or to XOR and OR {gate:?}",
+                gate.op
+            ))
+        }
+        Op::Or => {
+            let a = device.gate_with_output(gate.a);
+            let b = device.gate_with_output(gate.b);
+            let (a, b) = match (a, b) {
+                (Some(a), Some(b)) => (a, b),
+                _ => return Some(format!("[Or] gate is missing an input: {gate:?}")),
+            };
+            if a.op != Op::And || b.op != Op::And {
+                return Some(format!(
+                    "[Or] gate must only be connected to AND gates: {gate:?}"
+                ));
+            }
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Device {
+    values: HashMap<Wire, bool>,
+    gates: Vec<Gate>,
+    swap: Vec<(Wire, Wire)>,
+}
+
+impl Device {
+    /// Evaluate the gates until the values stop changing. If swapped outputs form a loop that keeps
+    /// changing, stop at the first values that repeat.
+    fn propagate(&mut self) {
+        // Every pass settles at least one more level of gates, so without loops the values stop
+        // changing after at most as many passes as there are gates.
+        let mut values = std::mem::take(&mut self.values);
+        for _ in 0..=self.gates.len() {
+            if !self.pass(&mut values) {
+                self.values = values;
+                return;
+            }
+        }
+
+        let pass = |values: &HashMap<Wire, bool>| {
+            let mut values = values.clone();
+            self.pass(&mut values);
+            Some(values)
+        };
+        let cycle = cycle::brent(values, pass).expect("passes never end, so their values repeat");
+        self.values = cycle.state;
+    }
+
+    /// Evaluate every gate whose inputs have a value once, in order. Returns whether a value changed.
+    fn pass(&self, values: &mut HashMap<Wire, bool>) -> bool {
+        let mut changed = false;
+        for gate in &self.gates {
+            let Some(a) = self.value_in(values, gate.a) else {
+                continue;
+            };
+            let Some(b) = self.value_in(values, gate.b) else {
+                continue;
+            };
+            let value = gate.op.eval(a, b);
+            changed |= values.insert(gate.out, value) != Some(value);
+        }
+        changed
+    }
+
+    fn get_value(&self, wire: Wire) -> Option<bool> {
+        self.value_in(&self.values, wire)
+    }
+
+    fn value_in(&self, values: &HashMap<Wire, bool>, wire: Wire) -> Option<bool> {
+        // Swap wire, if applicable
+        let wire = self
+            .swap
+            .iter()
+            .copied()
+            .find_map(|(a, b)| {
+                if a == wire {
+                    Some(b)
+                } else if b == wire {
+                    Some(a)
+                } else {
+                    None
+                }
+            })
+            .unwrap_or(wire);
+        values.get(&wire).copied()
+    }
+
+    fn gate_with_output(&self, wire: Wire) -> Option<Gate> {
+        self.gates
+            .iter()
+            .filter(move |g| g.out == wire)
+            .exactly_one()
+            .copied()
+            .ok()
+    }
+
+    fn integer(&self, c: char) -> usize {
+        let wires = self
+            .values
+            .keys()
+            .copied()
+            .filter(|w| w.0 == c)
+            .sorted_by_key(|&w| Reverse(w));
+        let values = wires
+            .map(|w| self.get_value(w).unwrap())
+            .map(|v| v as usize);
+        values.fold(0, |acc, v| (acc << 1) | v)
+    }
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (values, gates) = parse::split_once(input, "\n\n")?;
+        let values = values
+            .lines()
+            .map(parse_wire_value)
+            .collect::<Result<_, _>>()?;
+        let gates = gates.lines().map(Gate::parse).collect::<Result<_, _>>()?;
+        Ok(Self {
+            values,
+            gates,
+            swap: Vec::new(),
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Gate {
+    a: Wire,
+    b: Wire,
+    op: Op,
+    out: Wire,
+}
+
+impl Gate {
+    fn parse(line: &str) -> Result<Self, ParseError> {
+        let (input, out) = parse::split_once(line, " -> ")?;
+        let [a, op, b] = parse::words(input)?;
+        let a = Wire::parse(a)?;
+        let b = Wire::parse(b)?;
+        let op = Op::parse(op)?;
+        let out = Wire::parse(out)?;
+        Ok(Self { a, b, op, out })
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+struct Wire(char, char, char);
+
+impl Wire {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        match s.chars().collect_vec()[..] {
+            [a, b, c] => Ok(Self(a, b, c)),
+            _ => Err(ParseError::new(s, "wire needs 3 chars")),
+        }
+    }
+
+    fn is_input(self) -> bool {
+        self.0 == 'x' || self.0 == 'y'
+    }
+
+    fn is_output(self) -> bool {
+        self.0 == 'z'
+    }
+
+    /// The number of a wire like `z05`, or `None` if it does not end in two digits.
+    fn n(self) -> Option<u32> {
+        Some(self.1.to_digit(10)? * 10 + self.2.to_digit(10)?)
+    }
+}
+
+impl Ord for Wire {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.0, self.1, self.2).cmp(&(other.0, other.1, other.2))
+    }
+}
+
+impl PartialOrd for Wire {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Debug for Wire {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = format!("{}{}{}", self.0, self.1, self.2);
+        f.debug_tuple("Wire").field(&s).finish()
+    }
+}
+
+impl fmt::Display for Wire {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}{}", self.0, self.1, self.2)
+    }
+}
+
+fn parse_wire_value(s: &str) -> Result<(Wire, bool), ParseError> {
+    let (wire, value) = parse::split_once(s, ": ")?;
+    let wire = Wire::parse(wire)?;
+    let value: u8 = parse::number(value)?;
+    Ok((wire, value > 0))
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+enum Op {
+    And,
+    Or,
+    Xor,
+}
+
+impl Op {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        match s {
+            "AND" => Ok(Self::And),
+            "OR" => Ok(Self::Or),
+            "XOR" => Ok(Self::Xor),
+            _ => Err(ParseError::new(s, "unknown gate")),
+        }
+    }
+
+    fn eval(self, a: bool, b: bool) -> bool {
+        match self {
+            Self::And => a && b,
+            Self::Or => a || b,
+            Self::Xor => a ^ b,
+        }
+    }
+}
+
+/// The gates of a ripple-carry adder in random order, with random names for the internal wires.
+fn adder(rng: &mut Rng, bits: usize) -> Vec<Gate> {
+    let mut names = HashSet::new();
+    let mut wire = |rng: &mut Rng| loop {
+        let [a, b, c] = [b'w', b'z', b'z'].map(|last| rng.range(b'a'..=last) as char);
+        if names.insert((a, b, c)) {
+            return Wire(a, b, c);
+        }
+    };
+    let (x, y, z) = (
+        |n| numbered('x', n),
+        |n| numbered('y', n),
+        |n| numbered('z', n),
+    );
+    let gate = |a, op, b, out| Gate { a, b, op, out };
+
+    let mut carry = wire(rng);
+    let mut gates = vec![
+        gate(x(0), Op::Xor, y(0), z(0)),
+        gate(x(0), Op::And, y(0), carry),
+    ];
+    for n in 1..bits {
+        let (sum, both, carried) = (wire(rng), wire(rng), wire(rng));
+        let next = if n == bits - 1 { z(bits) } else { wire(rng) };
+        gates.extend([
+            gate(x(n), Op::Xor, y(n), sum),
+            gate(x(n), Op::And, y(n), both),
+            gate(sum, Op::Xor, carry, z(n)),
+            gate(sum, Op::And, carry, carried),
+            gate(both, Op::Or, carried, next),
+        ]);
+        carry = next;
+    }
+
+    for gate in &mut gates {
+        if rng.chance(0.5) {
+            (gate.a, gate.b) = (gate.b, gate.a);
+        }
+    }
+    rng.shuffle(&mut gates);
+    gates
+}
+
+/// A wire like `x05`.
+fn numbered(c: char, n: usize) -> Wire {
+    let digit = |d| char::from_digit(d as u32, 10).unwrap();
+    Wire(c, digit(n / 10), digit(n % 10))
+}
+
+/// Whether every wire gets a value, which is not the case if swapped outputs create a loop.
+fn computable(gates: &[Gate]) -> bool {
+    let mut known: HashSet<Wire> = gates
+        .iter()
+        .flat_map(|gate| [gate.a, gate.b])
+        .filter(|wire| wire.is_input())
+        .collect();
+    let mut remaining = gates.to_vec();
+    while !remaining.is_empty() {
+        let before = remaining.len();
+        remaining.retain(|gate| {
+            let ready = known.contains(&gate.a) && known.contains(&gate.b);
+            if ready {
+                known.insert(gate.out);
+            }
+            !ready
+        });
+        if remaining.len() == before {
+            return false;
+        }
+    }
+    true
+}
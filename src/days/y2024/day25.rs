@@ -0,0 +1,98 @@
+use crate::random::Rng;
+use crate::*;
+use itertools::{zip_eq, Itertools};
+use std::fmt::Display;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Parsed<'a> = (Vec<Heights>, Vec<Heights>);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let locks = input
+            .split("\n\n")
+            .filter(|lines| is_lock(lines))
+            .map(parse_heights)
+            .collect::<Result<_, _>>()?;
+        let keys = input
+            .split("\n\n")
+            .filter(|lines| !is_lock(lines))
+            .map(parse_heights)
+            .collect::<Result<_, _>>()?;
+        Ok((locks, keys))
+    }
+
+    fn part1((locks, keys): &Self::Parsed<'_>) -> impl Display {
+        locks
+            .iter()
+            .cartesian_product(keys)
+            .filter(|(l, k)| no_overlap(l, k))
+            .count()
+    }
+
+    /// There is no second puzzle on the last day.
+    fn part2(_: &Self::Parsed<'_>) -> impl Display {
+        ""
+    }
+
+    const EXAMPLES: &'static [Example] = &[Example::new(Part::One, "25-test", "3")];
+
+    /// `size` locks and keys with random heights.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut schematics = Vec::new();
+        for _ in 0..size.max(1) {
+            let heights: Vec<i64> = (0..5).map(|_| rng.range(0..=5)).collect();
+            let lock = rng.chance(0.5);
+            let mut schematic = String::new();
+            for row in 0..7 {
+                // Locks fill the rows from the top, keys from the bottom.
+                let row = if lock { row } else { 6 - row };
+                for &height in &heights {
+                    schematic.push(if row <= height { '#' } else { '.' });
+                }
+                schematic.push('\n');
+            }
+            schematics.push(schematic);
+        }
+        Some(schematics.join("\n"))
+    }
+}
+
+fn no_overlap(lock: &Heights, key: &Heights) -> bool {
+    zip_eq(lock, key).all(|(&l, &k)| l + k <= 5)
+}
+
+fn is_lock(lines: &str) -> bool {
+    lines.starts_with("#####")
+}
+
+pub type Heights = Vec<i64>;
+
+/// The number of columns of every lock and key.
+const WIDTH: usize = 5;
+
+fn parse_heights(lines: &str) -> Result<Heights, ParseError> {
+    if lines.lines().count() != 7 {
+        return Err(ParseError::new(lines, "expected 7 lines"));
+    }
+
+    // Every column counts its filled tiles except one, so a column without any is -1 and fits anything.
+    let mut heights = vec![-1; WIDTH];
+
+    for line in lines.lines() {
+        if line.chars().count() != WIDTH {
+            return Err(ParseError::new(line, format!("expected {WIDTH} columns")));
+        }
+        let filled = parse::chars(line, |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+        let indices = filled.into_iter().positions(|filled| filled);
+        for index in indices {
+            heights[index] += 1;
+        }
+    }
+
+    Ok(heights)
+}
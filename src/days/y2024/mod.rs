@@ -0,0 +1,58 @@
+//! The solutions of 2024.
+
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day20;
+mod day21;
+mod day22;
+mod day23;
+mod day24;
+mod day25;
+
+use crate::Day;
+
+/// All solved days of 2024, in order.
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(2024, 1),
+    Day::new::<day02::Day02>(2024, 2),
+    Day::new::<day03::Day03>(2024, 3),
+    Day::new::<day04::Day04>(2024, 4),
+    Day::new::<day05::Day05>(2024, 5),
+    Day::new::<day06::Day06>(2024, 6),
+    Day::new::<day07::Day07>(2024, 7),
+    Day::new::<day08::Day08>(2024, 8),
+    Day::new::<day09::Day09>(2024, 9),
+    Day::new::<day10::Day10>(2024, 10),
+    Day::new::<day11::Day11>(2024, 11),
+    Day::new::<day12::Day12>(2024, 12),
+    Day::new::<day13::Day13>(2024, 13),
+    Day::new::<day14::Day14>(2024, 14),
+    Day::new::<day15::Day15>(2024, 15),
+    Day::new::<day16::Day16>(2024, 16),
+    Day::new::<day17::Day17>(2024, 17),
+    Day::new::<day18::Day18>(2024, 18),
+    Day::new::<day19::Day19>(2024, 19),
+    Day::new::<day20::Day20>(2024, 20),
+    Day::new::<day21::Day21>(2024, 21),
+    Day::new::<day22::Day22>(2024, 22),
+    Day::new::<day23::Day23>(2024, 23),
+    Day::new::<day24::Day24>(2024, 24),
+    Day::new::<day25::Day25>(2024, 25),
+];
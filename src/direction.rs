@@ -0,0 +1,272 @@
+use glam::IVec2;
+use std::error::Error;
+use std::fmt::{self, Display};
+
+/// One of the 4 orthogonal directions on a grid where `y` grows downwards.
+///
+/// Directions are ordered clockwise starting with [`Direction::North`],
+/// which makes them usable as keys in ordered collections and search states.
+///
+/// ```rust
+/// # use aoc::Direction;
+/// # use glam::IVec2;
+/// let dir = Direction::try_from('^').unwrap();
+/// assert_eq!(dir, Direction::North);
+/// assert_eq!(dir.vec(), IVec2::new(0, -1));
+/// assert_eq!(dir.rotate_cw(), Direction::East);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// All directions, clockwise starting with north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// The unit vector pointing in this direction.
+    pub fn vec(self) -> IVec2 {
+        match self {
+            Self::North => IVec2::new(0, -1),
+            Self::East => IVec2::new(1, 0),
+            Self::South => IVec2::new(0, 1),
+            Self::West => IVec2::new(-1, 0),
+        }
+    }
+
+    /// Return the direction of a unit vector, or `None` for any other vector.
+    ///
+    /// ```rust
+    /// # use aoc::Direction;
+    /// # use glam::IVec2;
+    /// assert_eq!(Direction::from_vec(IVec2::new(-1, 0)), Some(Direction::West));
+    /// assert_eq!(Direction::from_vec(IVec2::new(1, 1)), None);
+    /// ```
+    pub fn from_vec(vec: IVec2) -> Option<Self> {
+        Self::ALL.into_iter().find(|dir| dir.vec() == vec)
+    }
+
+    /// Turn 90° clockwise.
+    pub fn rotate_cw(self) -> Self {
+        match self {
+            Self::North => Self::East,
+            Self::East => Self::South,
+            Self::South => Self::West,
+            Self::West => Self::North,
+        }
+    }
+
+    /// Turn 90° counterclockwise.
+    pub fn rotate_ccw(self) -> Self {
+        match self {
+            Self::North => Self::West,
+            Self::East => Self::North,
+            Self::South => Self::East,
+            Self::West => Self::South,
+        }
+    }
+
+    /// Turn around.
+    ///
+    /// ```rust
+    /// # use aoc::Direction;
+    /// for dir in Direction::ALL {
+    ///     assert_eq!(dir.opposite(), dir.rotate_cw().rotate_cw());
+    ///     assert_eq!(dir.opposite().vec(), -dir.vec());
+    /// }
+    /// ```
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::North => Self::South,
+            Self::East => Self::West,
+            Self::South => Self::North,
+            Self::West => Self::East,
+        }
+    }
+
+    /// Return true for north and south.
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Self::North | Self::South)
+    }
+
+    /// Return true for east and west.
+    pub fn is_horizontal(self) -> bool {
+        !self.is_vertical()
+    }
+
+    /// The arrow character for this direction, one of `^>v<`.
+    pub fn arrow(self) -> char {
+        match self {
+            Self::North => '^',
+            Self::East => '>',
+            Self::South => 'v',
+            Self::West => '<',
+        }
+    }
+
+    /// The compass letter for this direction, one of `NESW`.
+    pub fn letter(self) -> char {
+        match self {
+            Self::North => 'N',
+            Self::East => 'E',
+            Self::South => 'S',
+            Self::West => 'W',
+        }
+    }
+}
+
+impl From<Direction> for IVec2 {
+    fn from(dir: Direction) -> Self {
+        dir.vec()
+    }
+}
+
+/// Parse an arrow (`^>v<`) or a compass letter (`NESW`).
+///
+/// ```rust
+/// # use aoc::Direction;
+/// assert_eq!(Direction::try_from('v'), Ok(Direction::South));
+/// assert_eq!(Direction::try_from('W'), Ok(Direction::West));
+/// assert!(Direction::try_from('x').is_err());
+/// ```
+impl TryFrom<char> for Direction {
+    type Error = ParseDirectionError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Self::ALL
+            .into_iter()
+            .find(|dir| dir.arrow() == c || dir.letter() == c)
+            .ok_or(ParseDirectionError(c))
+    }
+}
+
+/// Print the arrow character.
+impl Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.arrow())
+    }
+}
+
+/// The character that failed to parse as a [`Direction`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseDirectionError(pub char);
+
+impl Display for ParseDirectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unexpected direction '{}'", self.0)
+    }
+}
+
+impl Error for ParseDirectionError {}
+
+/// One of the 8 orthogonal and diagonal directions on a grid where `y` grows downwards.
+///
+/// Directions are ordered clockwise starting with [`Direction8::North`].
+///
+/// ```rust
+/// # use aoc::{Direction, Direction8};
+/// # use glam::IVec2;
+/// assert_eq!(Direction8::NorthEast.vec(), IVec2::new(1, -1));
+/// assert_eq!(Direction8::NorthEast.rotate_cw(), Direction8::East);
+/// assert_eq!(Direction8::from(Direction::West), Direction8::West);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// All directions, clockwise starting with north.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    /// The vector pointing in this direction. Diagonals have a length of one in each axis.
+    pub fn vec(self) -> IVec2 {
+        match self {
+            Self::North => IVec2::new(0, -1),
+            Self::NorthEast => IVec2::new(1, -1),
+            Self::East => IVec2::new(1, 0),
+            Self::SouthEast => IVec2::new(1, 1),
+            Self::South => IVec2::new(0, 1),
+            Self::SouthWest => IVec2::new(-1, 1),
+            Self::West => IVec2::new(-1, 0),
+            Self::NorthWest => IVec2::new(-1, -1),
+        }
+    }
+
+    /// Return the direction of a vector, or `None` if it is not one of the 8 directions.
+    pub fn from_vec(vec: IVec2) -> Option<Self> {
+        Self::ALL.into_iter().find(|dir| dir.vec() == vec)
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Turn 45° clockwise.
+    pub fn rotate_cw(self) -> Self {
+        Self::ALL[(self.index() + 1) % 8]
+    }
+
+    /// Turn 45° counterclockwise.
+    pub fn rotate_ccw(self) -> Self {
+        Self::ALL[(self.index() + 7) % 8]
+    }
+
+    /// Turn around.
+    ///
+    /// ```rust
+    /// # use aoc::Direction8;
+    /// for dir in Direction8::ALL {
+    ///     assert_eq!(dir.opposite().vec(), -dir.vec());
+    /// }
+    /// ```
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self.index() + 4) % 8]
+    }
+
+    /// Return true for the 4 orthogonal directions.
+    pub fn is_orthogonal(self) -> bool {
+        self.index().is_multiple_of(2)
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(dir: Direction) -> Self {
+        match dir {
+            Direction::North => Self::North,
+            Direction::East => Self::East,
+            Direction::South => Self::South,
+            Direction::West => Self::West,
+        }
+    }
+}
+
+impl From<Direction8> for IVec2 {
+    fn from(dir: Direction8) -> Self {
+        dir.vec()
+    }
+}
@@ -0,0 +1,490 @@
+//! Undirected graphs over any hashable node labels, for puzzles that are about the graph itself,
+//! like groups of connected computers.
+//!
+//! Unlike [`search`](crate::search), which explores states lazily, the graph is built up front,
+//! so that algorithms can look at all nodes and edges.
+//!
+//! ```rust
+//! # use aoc::graph::Graph;
+//! let graph: Graph<&str> = [("a", "b"), ("b", "c"), ("c", "a"), ("c", "d")].into_iter().collect();
+//! assert_eq!(graph.triangles().len(), 1);
+//! assert_eq!(graph.maximum_clique().len(), 3);
+//! assert_eq!(graph.degree(&"c"), 3);
+//! ```
+
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+
+/// An undirected graph without loops or parallel edges.
+///
+/// Nodes and edges are kept in the order they were added, so every algorithm gives the same result
+/// for the same input.
+#[derive(Debug, Clone)]
+pub struct Graph<N> {
+    nodes: Vec<N>,
+    indices: HashMap<N, usize>,
+    /// The indices of the neighbors of every node, sorted.
+    neighbors: Vec<Vec<usize>>,
+    edges: usize,
+}
+
+/// How many neighbors the nodes of a graph have, see [`Graph::degrees`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Degrees {
+    pub min: usize,
+    pub max: usize,
+    pub mean: f64,
+    /// How many nodes have each degree.
+    pub counts: BTreeMap<usize, usize>,
+}
+
+impl<N> Default for Graph<N> {
+    fn default() -> Self {
+        Self {
+            nodes: Vec::new(),
+            indices: HashMap::new(),
+            neighbors: Vec::new(),
+            edges: 0,
+        }
+    }
+}
+
+impl<N: Eq + Hash + Clone> Graph<N> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a node without edges. Does nothing if it is already there.
+    pub fn add_node(&mut self, node: N) {
+        self.index(node);
+    }
+
+    /// Connect two nodes, adding them if needed. A node cannot be connected to itself, so that only adds the node.
+    pub fn add_edge(&mut self, a: N, b: N) {
+        let (a, b) = (self.index(a), self.index(b));
+        if a == b {
+            return;
+        }
+        if let Err(position) = self.neighbors[a].binary_search(&b) {
+            self.neighbors[a].insert(position, b);
+            let position = self.neighbors[b].binary_search(&a).unwrap_err();
+            self.neighbors[b].insert(position, a);
+            self.edges += 1;
+        }
+    }
+
+    fn index(&mut self, node: N) -> usize {
+        if let Some(&index) = self.indices.get(&node) {
+            return index;
+        }
+        let index = self.nodes.len();
+        self.nodes.push(node.clone());
+        self.indices.insert(node, index);
+        self.neighbors.push(Vec::new());
+        index
+    }
+
+    pub fn contains_node(&self, node: &N) -> bool {
+        self.indices.contains_key(node)
+    }
+
+    pub fn contains_edge(&self, a: &N, b: &N) -> bool {
+        match (self.indices.get(a), self.indices.get(b)) {
+            (Some(a), Some(b)) => self.neighbors[*a].binary_search(b).is_ok(),
+            _ => false,
+        }
+    }
+
+    /// All nodes, in the order they were added.
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.nodes.iter()
+    }
+
+    /// The nodes that are connected to the given one, in the order they were added. Empty if it is not in the graph.
+    pub fn neighbors(&self, node: &N) -> impl Iterator<Item = &N> {
+        let neighbors = self.indices.get(node).map(|&index| &self.neighbors[index]);
+        neighbors
+            .into_iter()
+            .flatten()
+            .map(|&neighbor| &self.nodes[neighbor])
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.edges
+    }
+
+    /// The number of neighbors of a node, 0 if it is not in the graph.
+    pub fn degree(&self, node: &N) -> usize {
+        self.indices
+            .get(node)
+            .map_or(0, |&index| self.neighbors[index].len())
+    }
+
+    /// The smallest, largest and mean degree, and how often each degree occurs. `None` if the graph is empty.
+    ///
+    /// ```rust
+    /// # use aoc::graph::Graph;
+    /// let graph: Graph<u8> = [(1, 2), (1, 3), (1, 4)].into_iter().collect();
+    /// let degrees = graph.degrees().unwrap();
+    /// assert_eq!((degrees.min, degrees.max, degrees.mean), (1, 3, 1.5));
+    /// assert_eq!(degrees.counts[&1], 3);
+    /// ```
+    pub fn degrees(&self) -> Option<Degrees> {
+        let mut counts = BTreeMap::new();
+        for neighbors in &self.neighbors {
+            *counts.entry(neighbors.len()).or_default() += 1;
+        }
+        Some(Degrees {
+            min: *counts.keys().next()?,
+            max: *counts.keys().next_back()?,
+            mean: 2.0 * self.edges as f64 / self.nodes.len() as f64,
+            counts,
+        })
+    }
+
+    /// Every set of three nodes that are all connected to each other, each set once.
+    ///
+    /// ```rust
+    /// # use aoc::graph::Graph;
+    /// // Two triangles that share the edge b-c
+    /// let graph: Graph<char> = [('a', 'b'), ('a', 'c'), ('b', 'c'), ('b', 'd'), ('c', 'd')].into_iter().collect();
+    /// let mut triangles: Vec<_> = graph.triangles().into_iter().map(|mut t| { t.sort(); t }).collect();
+    /// triangles.sort();
+    /// assert_eq!(triangles, [[&'a', &'b', &'c'], [&'b', &'c', &'d']]);
+    /// ```
+    pub fn triangles(&self) -> Vec<[&N; 3]> {
+        // Only go to neighbors with a higher index, so that every triangle is found from its lowest node.
+        // The common neighbors of a and b are found by walking both sorted lists at once.
+        let mut triangles = Vec::new();
+        for (a, neighbors_a) in self.neighbors.iter().enumerate() {
+            let higher = |neighbors: &[usize], than| neighbors.partition_point(|&n| n <= than);
+            for (i, &b) in neighbors_a.iter().enumerate().skip(higher(neighbors_a, a)) {
+                let neighbors_b = &self.neighbors[b];
+                let (mut i, mut j) = (i + 1, higher(neighbors_b, b));
+                while let (Some(&c_a), Some(&c_b)) = (neighbors_a.get(i), neighbors_b.get(j)) {
+                    match c_a.cmp(&c_b) {
+                        Ordering::Less => i += 1,
+                        Ordering::Greater => j += 1,
+                        Ordering::Equal => {
+                            triangles.push([&self.nodes[a], &self.nodes[b], &self.nodes[c_a]]);
+                            i += 1;
+                            j += 1;
+                        }
+                    }
+                }
+            }
+        }
+        triangles
+    }
+
+    /// Every clique that cannot be extended by another node, found with the Bron–Kerbosch algorithm with pivoting.
+    ///
+    /// A clique is a set of nodes that are all connected to each other. Nodes without edges are cliques on their own.
+    /// An empty graph has no cliques.
+    ///
+    /// <https://en.wikipedia.org/wiki/Bron%E2%80%93Kerbosch_algorithm>
+    ///
+    /// ```rust
+    /// # use aoc::graph::Graph;
+    /// let mut graph: Graph<u8> = [(1, 2), (1, 3), (2, 3), (3, 4)].into_iter().collect();
+    /// graph.add_node(5);
+    /// let mut cliques: Vec<Vec<u8>> = graph
+    ///     .maximal_cliques()
+    ///     .into_iter()
+    ///     .map(|clique| { let mut clique: Vec<u8> = clique.into_iter().copied().collect(); clique.sort(); clique })
+    ///     .collect();
+    /// cliques.sort();
+    /// assert_eq!(cliques, [vec![1, 2, 3], vec![3, 4], vec![5]]);
+    /// ```
+    pub fn maximal_cliques(&self) -> Vec<Vec<&N>> {
+        let count = self.nodes.len();
+        if count == 0 {
+            return Vec::new();
+        }
+        let neighbors: Vec<BitSet> = self
+            .neighbors
+            .iter()
+            .map(|neighbors| BitSet::from_indices(count, neighbors))
+            .collect();
+        let candidates = BitSet::from_indices(count, &(0..count).collect::<Vec<_>>());
+        let mut cliques = Vec::new();
+        bron_kerbosch(
+            &neighbors,
+            &mut Vec::new(),
+            candidates,
+            BitSet::new(count),
+            &mut cliques,
+        );
+        cliques
+            .into_iter()
+            .map(|clique| clique.into_iter().map(|node| &self.nodes[node]).collect())
+            .collect()
+    }
+
+    /// One of the largest cliques, see [`Graph::maximal_cliques`]. Empty if the graph is empty.
+    pub fn maximum_clique(&self) -> Vec<&N> {
+        self.maximal_cliques()
+            .into_iter()
+            .rev()
+            .max_by_key(|clique| clique.len())
+            .unwrap_or_default()
+    }
+
+    /// The groups of nodes that are connected by paths, each in the order they were reached.
+    ///
+    /// ```rust
+    /// # use aoc::graph::Graph;
+    /// let mut graph: Graph<u8> = [(1, 2), (3, 4), (2, 5)].into_iter().collect();
+    /// graph.add_node(6);
+    /// assert_eq!(graph.components(), [vec![&1, &2, &5], vec![&3, &4], vec![&6]]);
+    /// ```
+    pub fn components(&self) -> Vec<Vec<&N>> {
+        let mut seen = vec![false; self.nodes.len()];
+        let mut components = Vec::new();
+        for start in 0..self.nodes.len() {
+            if seen[start] {
+                continue;
+            }
+            seen[start] = true;
+            let mut component = vec![start];
+            let mut next = 0;
+            while let Some(&node) = component.get(next) {
+                next += 1;
+                for &neighbor in &self.neighbors[node] {
+                    if !seen[neighbor] {
+                        seen[neighbor] = true;
+                        component.push(neighbor);
+                    }
+                }
+            }
+            components.push(
+                component
+                    .into_iter()
+                    .map(|node| &self.nodes[node])
+                    .collect(),
+            );
+        }
+        components
+    }
+}
+
+/// Extend the clique with all sets of candidates that form a maximal clique together with it.
+/// `excluded` are the nodes that could extend the clique too, but whose cliques were already reported.
+fn bron_kerbosch(
+    neighbors: &[BitSet],
+    clique: &mut Vec<usize>,
+    mut candidates: BitSet,
+    mut excluded: BitSet,
+    cliques: &mut Vec<Vec<usize>>,
+) {
+    // Every maximal clique contains the pivot or one of its non-neighbors,
+    // so only those need to be tried as the next node.
+    let Some(pivot) = candidates
+        .iter()
+        .chain(excluded.iter())
+        .max_by_key(|&node| neighbors[node].intersection_len(&candidates))
+    else {
+        cliques.push(clique.clone());
+        return;
+    };
+    let tried: Vec<usize> = candidates.difference(&neighbors[pivot]).collect();
+
+    for node in tried {
+        clique.push(node);
+        bron_kerbosch(
+            neighbors,
+            clique,
+            candidates.intersection(&neighbors[node]),
+            excluded.intersection(&neighbors[node]),
+            cliques,
+        );
+        clique.pop();
+        candidates.remove(node);
+        excluded.insert(node);
+    }
+}
+
+/// A set of node indices below a fixed count, with one bit for every index.
+#[derive(Debug, Clone)]
+struct BitSet {
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    fn new(count: usize) -> Self {
+        Self {
+            words: vec![0; count.div_ceil(64)],
+        }
+    }
+
+    fn from_indices(count: usize, indices: &[usize]) -> Self {
+        let mut set = Self::new(count);
+        for &index in indices {
+            set.insert(index);
+        }
+        set
+    }
+
+    fn insert(&mut self, index: usize) {
+        self.words[index / 64] |= 1 << (index % 64);
+    }
+
+    fn remove(&mut self, index: usize) {
+        self.words[index / 64] &= !(1 << (index % 64));
+    }
+
+    fn intersection(&self, other: &Self) -> Self {
+        Self {
+            words: self
+                .words
+                .iter()
+                .zip(&other.words)
+                .map(|(a, b)| a & b)
+                .collect(),
+        }
+    }
+
+    fn intersection_len(&self, other: &Self) -> u32 {
+        self.words
+            .iter()
+            .zip(&other.words)
+            .map(|(a, b)| (a & b).count_ones())
+            .sum()
+    }
+
+    /// The indices in ascending order.
+    fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        bits(self.words.iter().copied())
+    }
+
+    /// The indices that are not in `other`, in ascending order.
+    fn difference<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = usize> + 'a {
+        bits(self.words.iter().zip(&other.words).map(|(a, b)| a & !b))
+    }
+}
+
+/// The indices of the set bits of consecutive words.
+fn bits(words: impl Iterator<Item = u64>) -> impl Iterator<Item = usize> {
+    words.enumerate().flat_map(|(i, mut word)| {
+        std::iter::from_fn(move || {
+            (word != 0).then(|| {
+                let bit = word.trailing_zeros() as usize;
+                word &= word - 1;
+                i * 64 + bit
+            })
+        })
+    })
+}
+
+/// Build a graph from its edges.
+impl<N: Eq + Hash + Clone> FromIterator<(N, N)> for Graph<N> {
+    fn from_iter<I: IntoIterator<Item = (N, N)>>(edges: I) -> Self {
+        let mut graph = Self::new();
+        for (a, b) in edges {
+            graph.add_edge(a, b);
+        }
+        graph
+    }
+}
+
+/// The cliques with sorted nodes in sorted order, to compare them regardless of the order they were found in.
+#[cfg(test)]
+fn sorted_cliques(cliques: Vec<Vec<&char>>) -> Vec<Vec<&char>> {
+    let mut cliques: Vec<_> = cliques
+        .into_iter()
+        .map(|mut clique| {
+            clique.sort();
+            clique
+        })
+        .collect();
+    cliques.sort();
+    cliques
+}
+
+#[test]
+fn test_empty_graph() {
+    let graph: Graph<char> = Graph::new();
+    assert_eq!((graph.node_count(), graph.edge_count()), (0, 0));
+    assert_eq!(graph.degrees(), None);
+    assert!(graph.triangles().is_empty());
+    assert!(graph.maximal_cliques().is_empty());
+    assert!(graph.maximum_clique().is_empty());
+    assert!(graph.components().is_empty());
+    assert_eq!(graph.neighbors(&'a').count(), 0);
+    assert!(!graph.contains_edge(&'a', &'b'));
+}
+
+#[test]
+fn test_isolated_nodes() {
+    let mut graph = Graph::new();
+    graph.add_node('a');
+    graph.add_edge('b', 'b');
+    graph.add_node('a');
+    assert_eq!((graph.node_count(), graph.edge_count()), (2, 0));
+    assert!(!graph.contains_edge(&'b', &'b'));
+    assert_eq!(graph.degree(&'a'), 0);
+    assert_eq!(graph.degrees().unwrap().counts, BTreeMap::from([(0, 2)]));
+    assert!(graph.triangles().is_empty());
+    assert_eq!(sorted_cliques(graph.maximal_cliques()), [[&'a'], [&'b']]);
+    assert_eq!(graph.maximum_clique(), [&'a']);
+    assert_eq!(graph.components(), [[&'a'], [&'b']]);
+}
+
+#[test]
+fn test_parallel_edges_count_once() {
+    let graph: Graph<char> = [('a', 'b'), ('b', 'a'), ('a', 'b')].into_iter().collect();
+    assert_eq!(graph.edge_count(), 1);
+    assert_eq!(graph.neighbors(&'a').collect::<Vec<_>>(), [&'b']);
+    assert_eq!(graph.degrees().unwrap().mean, 1.0);
+}
+
+#[test]
+fn test_equally_large_cliques() {
+    // Two triangles that share the node c, and an edge that is a smaller clique.
+    let graph: Graph<char> = [
+        ('d', 'e'),
+        ('c', 'd'),
+        ('c', 'e'),
+        ('a', 'b'),
+        ('b', 'c'),
+        ('a', 'c'),
+        ('e', 'f'),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(graph.triangles().len(), 2);
+    assert_eq!(
+        sorted_cliques(graph.maximal_cliques()),
+        [
+            vec![&'a', &'b', &'c'],
+            vec![&'c', &'d', &'e'],
+            vec![&'e', &'f']
+        ]
+    );
+    // Which of the largest cliques is returned only depends on the order the edges were added in.
+    let largest = graph.maximum_clique();
+    assert_eq!(largest.len(), 3);
+    for _ in 0..10 {
+        assert_eq!(graph.clone().maximum_clique(), largest);
+    }
+}
+
+#[test]
+fn test_cliques_across_words() {
+    // More than 64 nodes, so that the bit sets of the clique search need several words.
+    let mut graph: Graph<usize> = (0..100).map(|n| (n, n + 1)).collect();
+    for (a, b) in [(10, 70), (10, 90), (70, 90)] {
+        graph.add_edge(a, b);
+    }
+    assert_eq!(graph.triangles(), [[&10, &70, &90]]);
+    let mut largest: Vec<usize> = graph.maximum_clique().into_iter().copied().collect();
+    largest.sort();
+    assert_eq!(largest, [10, 70, 90]);
+    // Every edge of the path, and the triangle.
+    assert_eq!(graph.maximal_cliques().len(), 101);
+}
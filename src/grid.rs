@@ -0,0 +1,298 @@
+use crate::parse::{self, ParseError};
+use crate::{DIRECTIONS4, DIRECTIONS8};
+use glam::IVec2;
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+/// A dense, rectangular grid of tiles addressed by [`IVec2`].
+///
+/// `x` grows to the right and `y` grows downwards, like the lines of a puzzle input.
+///
+/// ```rust
+/// # use aoc::Grid;
+/// # use glam::IVec2;
+/// let grid = Grid::parse("#.\n.#", |c| c == '#');
+/// assert_eq!(grid.size(), IVec2::new(2, 2));
+/// assert_eq!(grid.get(IVec2::new(1, 1)), Some(&true));
+/// assert_eq!(grid.get(IVec2::new(1, 0)), Some(&false));
+/// assert_eq!(grid.get(IVec2::new(2, 0)), None);
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Grid<T> {
+    size: IVec2,
+    tiles: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Create a grid of the given size where every tile is `fill`.
+    ///
+    /// ```rust
+    /// # use aoc::Grid;
+    /// # use glam::IVec2;
+    /// let grid = Grid::new(IVec2::new(3, 2), 0);
+    /// assert_eq!(grid.to_string(), "000\n000\n");
+    /// ```
+    pub fn new(size: IVec2, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        assert!(size.x >= 0 && size.y >= 0, "negative grid size {size}");
+        let tiles = vec![fill; (size.x * size.y) as usize];
+        Self { size, tiles }
+    }
+
+    /// Parse a grid from lines of characters, converting every character with `parse_tile`.
+    ///
+    /// Panics if the lines have different lengths to keep things simple.
+    ///
+    /// ```rust
+    /// # use aoc::Grid;
+    /// # use glam::IVec2;
+    /// let grid = Grid::parse("12\n34\n", |c| c.to_digit(10).unwrap());
+    /// assert_eq!(grid[IVec2::new(0, 1)], 3);
+    /// ```
+    pub fn parse(input: &str, mut parse_tile: impl FnMut(char) -> T) -> Self {
+        match Self::try_parse(input, |c| Some(parse_tile(c))) {
+            Ok(grid) => grid,
+            Err(e) => panic!("{}", e.locate(input)),
+        }
+    }
+
+    /// Parse a grid from lines of characters, converting every character with `parse_tile`.
+    ///
+    /// Fails if `parse_tile` returns `None` or if the lines have different lengths.
+    ///
+    /// ```rust
+    /// # use aoc::Grid;
+    /// let grid = Grid::try_parse("#.\n.#", |c| match c {
+    ///     '#' => Some(true),
+    ///     '.' => Some(false),
+    ///     _ => None,
+    /// });
+    /// assert!(grid.is_ok());
+    ///
+    /// let error = Grid::try_parse("#.\n.x", |c| (c == '#' || c == '.').then_some(c)).unwrap_err();
+    /// assert_eq!(error.text(), "x");
+    /// # assert_eq!(error.locate("#.\n.x").column(), Some(2));
+    ///
+    /// let error = Grid::try_parse("#.\n.", Some).unwrap_err();
+    /// assert_eq!(error.message(), "expected 2 tiles, found 1");
+    /// ```
+    pub fn try_parse(
+        input: &str,
+        mut parse_tile: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut tiles = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for line in input.lines() {
+            let row = parse::chars(line, &mut parse_tile)?;
+            match width {
+                None => width = Some(row.len()),
+                Some(width) if width == row.len() => (),
+                Some(width) => {
+                    let message = format!("expected {width} tiles, found {}", row.len());
+                    return Err(ParseError::new(line, message));
+                }
+            }
+            tiles.extend(row);
+            height += 1;
+        }
+
+        let size = IVec2::new(width.unwrap_or(0) as i32, height);
+        Ok(Self { size, tiles })
+    }
+
+    /// Width and height of the grid.
+    pub fn size(&self) -> IVec2 {
+        self.size
+    }
+
+    pub fn width(&self) -> i32 {
+        self.size.x
+    }
+
+    pub fn height(&self) -> i32 {
+        self.size.y
+    }
+
+    /// Return true if the position lies inside the grid.
+    ///
+    /// ```rust
+    /// # use aoc::Grid;
+    /// # use glam::IVec2;
+    /// let grid = Grid::new(IVec2::new(2, 3), ());
+    /// assert!(grid.contains(IVec2::new(1, 2)));
+    /// assert!(!grid.contains(IVec2::new(2, 2)));
+    /// assert!(!grid.contains(IVec2::new(0, -1)));
+    /// ```
+    pub fn contains(&self, pos: IVec2) -> bool {
+        pos.x >= 0 && pos.y >= 0 && pos.x < self.size.x && pos.y < self.size.y
+    }
+
+    fn index_of(&self, pos: IVec2) -> Option<usize> {
+        self.contains(pos)
+            .then(|| (pos.y * self.size.x + pos.x) as usize)
+    }
+
+    fn position_of(&self, index: usize) -> IVec2 {
+        let index = index as i32;
+        IVec2::new(index % self.size.x, index / self.size.x)
+    }
+
+    /// Return the tile at the position, or `None` if it is out of bounds.
+    pub fn get(&self, pos: IVec2) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.tiles[i])
+    }
+
+    /// Return the tile at the position mutably, or `None` if it is out of bounds.
+    pub fn get_mut(&mut self, pos: IVec2) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.tiles[i])
+    }
+
+    /// All positions in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = IVec2> + use<'_, T> {
+        (0..self.tiles.len()).map(|i| self.position_of(i))
+    }
+
+    /// All tiles together with their position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (IVec2, &T)> {
+        self.tiles
+            .iter()
+            .enumerate()
+            .map(|(i, tile)| (self.position_of(i), tile))
+    }
+
+    /// Return the position of the first tile matching the predicate, searching row by row.
+    ///
+    /// ```rust
+    /// # use aoc::Grid;
+    /// # use glam::IVec2;
+    /// let grid = Grid::parse("..\n.S", |c| c);
+    /// assert_eq!(grid.find(|&c| c == 'S'), Some(IVec2::new(1, 1)));
+    /// assert_eq!(grid.find(|&c| c == 'E'), None);
+    /// ```
+    pub fn find(&self, predicate: impl FnMut(&T) -> bool) -> Option<IVec2> {
+        self.tiles
+            .iter()
+            .position(predicate)
+            .map(|i| self.position_of(i))
+    }
+
+    /// The up to 4 orthogonal neighbors of a position that lie inside the grid.
+    ///
+    /// ```rust
+    /// # use aoc::Grid;
+    /// # use glam::IVec2;
+    /// let grid = Grid::new(IVec2::new(3, 3), ());
+    /// assert_eq!(grid.neighbors4(IVec2::new(1, 1)).count(), 4);
+    /// assert_eq!(grid.neighbors4(IVec2::new(0, 0)).count(), 2);
+    /// ```
+    pub fn neighbors4(&self, pos: IVec2) -> impl Iterator<Item = IVec2> + use<'_, T> {
+        DIRECTIONS4
+            .into_iter()
+            .map(move |dir| pos + dir)
+            .filter(|&pos| self.contains(pos))
+    }
+
+    /// The up to 8 orthogonal and diagonal neighbors of a position that lie inside the grid.
+    ///
+    /// ```rust
+    /// # use aoc::Grid;
+    /// # use glam::IVec2;
+    /// let grid = Grid::new(IVec2::new(3, 3), ());
+    /// assert_eq!(grid.neighbors8(IVec2::new(1, 1)).count(), 8);
+    /// assert_eq!(grid.neighbors8(IVec2::new(0, 0)).count(), 3);
+    /// ```
+    pub fn neighbors8(&self, pos: IVec2) -> impl Iterator<Item = IVec2> + use<'_, T> {
+        DIRECTIONS8
+            .into_iter()
+            .map(move |dir| pos + dir)
+            .filter(|&pos| self.contains(pos))
+    }
+
+    /// Iterate over the rows from top to bottom.
+    ///
+    /// ```rust
+    /// # use aoc::Grid;
+    /// let grid = Grid::parse("ab\ncd", |c| c);
+    /// let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+    /// assert_eq!(rows, ["ab", "cd"]);
+    /// ```
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on zero, but there are no tiles to iterate then anyway.
+        self.tiles.chunks(self.size.x.max(1) as usize)
+    }
+
+    /// Iterate over the tiles of a single column from top to bottom.
+    ///
+    /// ```rust
+    /// # use aoc::Grid;
+    /// let grid = Grid::parse("ab\ncd", |c| c);
+    /// let column: String = grid.column(1).collect();
+    /// assert_eq!(column, "bd");
+    /// ```
+    pub fn column(&self, x: i32) -> impl Iterator<Item = &T> {
+        assert!(x >= 0 && x < self.size.x, "column {x} out of bounds");
+        self.tiles
+            .iter()
+            .skip(x as usize)
+            .step_by(self.size.x as usize)
+    }
+
+    /// Iterate over the columns from left to right.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.size.x).map(|x| self.column(x))
+    }
+
+    /// Create a new grid of the same size by converting every tile.
+    ///
+    /// ```rust
+    /// # use aoc::Grid;
+    /// let grid = Grid::parse("#.", |c| c).map(|&c| c == '#');
+    /// assert_eq!(grid.to_string(), "truefalse\n");
+    /// ```
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            size: self.size,
+            tiles: self.tiles.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<IVec2> for Grid<T> {
+    type Output = T;
+
+    /// Panics if the position is out of bounds.
+    fn index(&self, pos: IVec2) -> &T {
+        match self.get(pos) {
+            Some(tile) => tile,
+            None => panic!("{pos} is out of bounds for grid of size {}", self.size),
+        }
+    }
+}
+
+impl<T> IndexMut<IVec2> for Grid<T> {
+    /// Panics if the position is out of bounds.
+    fn index_mut(&mut self, pos: IVec2) -> &mut T {
+        let size = self.size;
+        match self.get_mut(pos) {
+            Some(tile) => tile,
+            None => panic!("{pos} is out of bounds for grid of size {size}"),
+        }
+    }
+}
+
+/// Print the grid line by line, the way it would appear in a puzzle input.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for tile in row {
+                write!(f, "{tile}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,140 @@
+//! Loading puzzle inputs at runtime.
+//!
+//! Inputs live in the `input` directory next to `Cargo.toml`, with one directory per year:
+//! `input/2024/05` is the real input of day 5 of 2024 and `input/2024/05-test` its example.
+//! Set `AOC_INPUT_DIR` to use another directory.
+//!
+//! The runner accepts `--input <path>` to read a different file, or `--input -` to read stdin.
+
+use crate::parse::{self, ParseError};
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+/// Environment variable that overrides the input directory.
+pub const DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// The text of a puzzle input together with a name for error messages.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    name: String,
+    text: String,
+}
+
+impl Input {
+    /// Create an input from text that did not come from a file.
+    pub fn new(name: impl Into<String>, text: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            text: text.into(),
+        }
+    }
+
+    /// Where the input came from, usually its path.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The contents of the input.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Apply a solution to this input. Parse errors point at the line and column in the input.
+    ///
+    /// ```rust
+    /// # use aoc::input::Input;
+    /// # use aoc::parse;
+    /// let input = Input::new("example", "1\n2\nthree\n");
+    /// let sum = input.solve(|text| {
+    ///     text.lines().map(parse::number::<u32>).sum::<Result<u32, _>>()
+    /// });
+    /// assert_eq!(sum.unwrap_err().to_string(), "example:3:1: invalid number: 'three'");
+    /// ```
+    pub fn solve<'a, T>(
+        &'a self,
+        solve: impl FnOnce(&'a str) -> Result<T, ParseError>,
+    ) -> Result<T, ParseError> {
+        parse::run(&self.name, &self.text, solve)
+    }
+}
+
+/// An input could not be loaded.
+#[derive(Debug)]
+pub enum InputError {
+    /// The input file does not exist.
+    Missing(PathBuf),
+    /// The input exists but could not be read.
+    Io(String, io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing(path) => write!(
+                f,
+                "input file '{}' does not exist, create it or pass --input <path>",
+                path.display()
+            ),
+            Self::Io(name, e) => write!(f, "could not read input '{name}': {e}"),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(_, e) => Some(e),
+            Self::Missing(_) => None,
+        }
+    }
+}
+
+/// The directory that contains the inputs.
+pub fn dir() -> PathBuf {
+    match env::var_os(DIR_VAR) {
+        Some(dir) => PathBuf::from(dir),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("input"),
+    }
+}
+
+/// The directory that contains the inputs of a year.
+pub fn year_dir(year: u16) -> PathBuf {
+    dir().join(year.to_string())
+}
+
+/// Read a file from the input directory of a year, like `"05"` or `"05-test"`.
+pub fn file(year: u16, name: &str) -> Result<Input, InputError> {
+    path(year_dir(year).join(name))
+}
+
+/// Read an input from any path.
+pub fn path(path: impl AsRef<Path>) -> Result<Input, InputError> {
+    let path = path.as_ref();
+    match fs::read_to_string(path) {
+        Ok(text) => Ok(Input::new(path.display().to_string(), text)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Err(InputError::Missing(path.into())),
+        Err(e) => Err(InputError::Io(path.display().to_string(), e)),
+    }
+}
+
+/// Read an input from stdin.
+pub fn stdin() -> Result<Input, InputError> {
+    let mut text = String::new();
+    io::stdin()
+        .read_to_string(&mut text)
+        .map_err(|e| InputError::Io("<stdin>".into(), e))?;
+    Ok(Input::new("<stdin>", text))
+}
+
+/// Read the real input of a day from the input directory.
+pub fn real(year: u16, day: u8) -> Result<Input, InputError> {
+    file(year, &format!("{day:02}"))
+}
+
+/// Read the example input of a day from the input directory.
+pub fn example(year: u16, day: u8) -> Result<Input, InputError> {
+    file(year, &format!("{day:02}-test"))
+}
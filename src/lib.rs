@@ -1,5 +1,30 @@
 //! This library contains useful helper functions that may be useful in several problems.
 
+pub mod answers;
+pub mod artifact;
+pub mod bench;
+pub mod cycle;
+pub mod days;
+pub mod graph;
+pub mod input;
+pub mod linalg;
+pub mod math;
+pub mod parallel;
+pub mod parse;
+pub mod random;
+pub mod regions;
+pub mod search;
+pub mod submit;
+
+mod direction;
+mod grid;
+mod solution;
+
+pub use direction::{Direction, Direction8, ParseDirectionError};
+pub use grid::Grid;
+pub use parse::ParseError;
+pub use solution::{Answer, Day, Example, Part, Solution, Solved, Timings};
+
 use glam::IVec2;
 use std::{
     fmt::{Debug, Display},
@@ -7,37 +32,6 @@ use std::{
     str::FromStr,
 };
 
-/// Parse a whitespace separated list of things.
-///
-/// This works with any type that implements [`FromStr`].
-///
-/// Panics on parse error to keep things simple.
-///
-/// ```rust
-/// # use aoc::parse_ws_separated;
-/// let mut nums = parse_ws_separated("1 2 3");
-/// assert_eq!(nums.next(), Some(1));
-/// assert_eq!(nums.next(), Some(2));
-/// assert_eq!(nums.next(), Some(3));
-/// assert_eq!(nums.next(), None);
-/// ```
-///
-/// ```rust
-/// # use aoc::parse_ws_separated;
-/// # use std::net::Ipv4Addr;
-/// let mut ips = parse_ws_separated("127.0.0.1      10.0.0.1");
-/// assert_eq!(ips.next(), Some(Ipv4Addr::new(127, 0, 0, 1)));
-/// assert_eq!(ips.next(), Some(Ipv4Addr::new(10, 0, 0, 1)));
-/// assert_eq!(ips.next(), None);
-/// ```
-pub fn parse_ws_separated<T>(s: &str) -> impl DoubleEndedIterator<Item = T> + '_
-where
-    T: FromStr,
-    <T as FromStr>::Err: Debug,
-{
-    s.split_ascii_whitespace().map(|s| s.parse().unwrap())
-}
-
 /// Concatenate two things.
 ///
 /// Panics if the concatenation is not be parseable to keep things simple.
@@ -56,6 +50,8 @@ where
 
 /// Return the greatest common divisor.
 ///
+/// See [`math::checked_gcd`] for a version that cannot overflow.
+///
 /// <https://en.wikipedia.org/wiki/Euclidean_algorithm>
 ///
 /// ```rust
@@ -78,6 +74,8 @@ where
 
 /// Return the least common multiple.
 ///
+/// See [`math::checked_lcm`] for a version that cannot overflow.
+///
 /// <https://en.wikipedia.org/wiki/Least_common_multiple>
 ///
 /// ```rust
@@ -93,21 +91,6 @@ where
     a / gcd * b
 }
 
-/// Given a function and a name of a file in the `input` directory,
-/// assert that the function applied to the contents of the file returns the expected result.
-#[macro_export]
-macro_rules! assert_example {
-    ($solve:ident, $file:expr, $expected:expr) => {
-        assert_eq!(
-            $solve(include_str!(concat!("../../input/", $file))),
-            $expected,
-            "{}, {}",
-            stringify!($solve),
-            $file,
-        )
-    };
-}
-
 /// 4 directions. Start pointing right and go CCW.
 pub const DIRECTIONS4: [IVec2; 4] = [
     IVec2::new(1, 0),
@@ -0,0 +1,443 @@
+//! Exact solutions of systems of linear equations, with fractions instead of floats.
+//!
+//! ```rust
+//! # use aoc::linalg::{self, Rational, Solutions};
+//! // 2x + y = 5 and x - y = 1
+//! let equations = [[2, 1], [1, -1]].map(|row| row.map(Rational::from));
+//! let rhs = [5, 1].map(Rational::from);
+//! assert_eq!(linalg::solve(&equations, &rhs), Solutions::Unique([2, 1].map(Rational::from)));
+//! ```
+
+use crate::math;
+use std::cmp::Ordering;
+use std::fmt::{self, Display};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A fraction in lowest terms, with a positive denominator.
+///
+/// The arithmetic panics if a numerator or denominator does not fit into an `i128`.
+///
+/// ```rust
+/// # use aoc::linalg::Rational;
+/// let third = Rational::new(2, 6);
+/// assert_eq!(third, Rational::new(-1, -3));
+/// assert_eq!(third + Rational::new(1, 6), Rational::new(1, 2));
+/// assert_eq!((third * Rational::from(3)).to_integer(), Some(1));
+/// assert_eq!(third.to_string(), "1/3");
+/// assert!(third < Rational::new(1, 2));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numer: i128,
+    denom: i128,
+}
+
+impl Rational {
+    pub const ZERO: Self = Self { numer: 0, denom: 1 };
+    pub const ONE: Self = Self { numer: 1, denom: 1 };
+
+    /// The fraction `numer / denom`. Panics if the denominator is 0.
+    pub fn new(numer: i128, denom: i128) -> Self {
+        assert!(denom != 0, "the denominator is 0");
+        let gcd = crate::gcd(numer.unsigned_abs(), denom.unsigned_abs()) as i128;
+        let sign = denom.signum();
+        Self {
+            numer: numer / gcd * sign,
+            denom: denom / gcd * sign,
+        }
+    }
+
+    pub fn numer(self) -> i128 {
+        self.numer
+    }
+
+    /// The denominator, which is always positive.
+    pub fn denom(self) -> i128 {
+        self.denom
+    }
+
+    pub fn is_integer(self) -> bool {
+        self.denom == 1
+    }
+
+    /// The value if it is a whole number.
+    pub fn to_integer(self) -> Option<i128> {
+        self.is_integer().then_some(self.numer)
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(n: i64) -> Self {
+        Self {
+            numer: n.into(),
+            denom: 1,
+        }
+    }
+}
+
+/// The result of an operation, or a panic if it does not fit.
+fn fits(n: Option<i128>) -> i128 {
+    n.expect("the fraction does not fit into an i128")
+}
+
+impl Add for Rational {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let numer = fits(self.numer.checked_mul(other.denom))
+            .checked_add(fits(other.numer.checked_mul(self.denom)));
+        Self::new(fits(numer), fits(self.denom.checked_mul(other.denom)))
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        // Cancel first, so the products stay as small as possible.
+        let a = Self::new(self.numer, other.denom);
+        let b = Self::new(other.numer, self.denom);
+        Self::new(
+            fits(a.numer.checked_mul(b.numer)),
+            fits(a.denom.checked_mul(b.denom)),
+        )
+    }
+}
+
+impl Div for Rational {
+    type Output = Self;
+
+    /// Panics when dividing by zero.
+    fn div(self, other: Self) -> Self {
+        assert!(other.numer != 0, "division by zero");
+        self * Self::new(other.denom, other.numer)
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            numer: fits(self.numer.checked_neg()),
+            denom: self.denom,
+        }
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare((self.numer, self.denom), (other.numer, other.denom))
+    }
+}
+
+/// Compare two fractions with positive denominators without multiplying them, which could overflow.
+///
+/// If the whole parts are the same, the fractional parts compare the other way around as their reciprocals.
+fn compare((n1, d1): (i128, i128), (n2, d2): (i128, i128)) -> Ordering {
+    let whole = n1.div_euclid(d1).cmp(&n2.div_euclid(d2));
+    let (r1, r2) = (n1.rem_euclid(d1), n2.rem_euclid(d2));
+    whole.then_with(|| match (r1, r2) {
+        (0, 0) => Ordering::Equal,
+        (0, _) => Ordering::Less,
+        (_, 0) => Ordering::Greater,
+        _ => compare((d2, r2), (d1, r1)),
+    })
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
+
+/// The solutions of a system of linear equations with `N` unknowns.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Solutions<const N: usize> {
+    /// Exactly one value for every unknown.
+    Unique([Rational; N]),
+    /// The equations contradict each other.
+    Inconsistent,
+    /// There are infinitely many solutions, because there are not enough independent equations.
+    Underdetermined,
+}
+
+/// Solve the equations `equations[i] · x = rhs[i]` with Gauss-Jordan elimination.
+///
+/// There can be any number of equations. Panics if there are not as many right hand sides as equations.
+///
+/// <https://en.wikipedia.org/wiki/Gaussian_elimination>
+///
+/// ```rust
+/// # use aoc::linalg::{self, Rational, Solutions};
+/// let r = |rows: &[[i64; 2]]| rows.iter().map(|row| row.map(Rational::from)).collect::<Vec<_>>();
+/// let rhs = |values: &[i64]| values.iter().copied().map(Rational::from).collect::<Vec<_>>();
+///
+/// // x + 2y = 3 twice, so y can be anything
+/// assert_eq!(linalg::solve(&r(&[[1, 2], [2, 4]]), &rhs(&[3, 6])), Solutions::Underdetermined);
+/// // x + 2y = 3 and x + 2y = 4
+/// assert_eq!(linalg::solve(&r(&[[1, 2], [2, 4]]), &rhs(&[3, 8])), Solutions::Inconsistent);
+/// // 3x = 1, y = 0 and 0 = 0
+/// let third = Solutions::Unique([Rational::new(1, 3), Rational::ZERO]);
+/// assert_eq!(linalg::solve(&r(&[[3, 0], [0, 1], [0, 0]]), &rhs(&[1, 0, 0])), third);
+/// ```
+pub fn solve<const N: usize>(equations: &[[Rational; N]], rhs: &[Rational]) -> Solutions<N> {
+    assert_eq!(
+        equations.len(),
+        rhs.len(),
+        "every equation needs a right hand side"
+    );
+    let mut rows: Vec<([Rational; N], Rational)> =
+        equations.iter().copied().zip(rhs.iter().copied()).collect();
+
+    // Bring the rows into reduced row echelon form, one pivot column at a time.
+    let mut rank = 0;
+    let mut pivots = Vec::new();
+    for column in 0..N {
+        let Some(pivot) = (rank..rows.len()).find(|&row| rows[row].0[column] != Rational::ZERO)
+        else {
+            continue;
+        };
+        rows.swap(rank, pivot);
+        let factor = rows[rank].0[column];
+        let (coefficients, value) = &mut rows[rank];
+        coefficients.iter_mut().for_each(|c| *c = *c / factor);
+        *value = *value / factor;
+
+        let (pivot_coefficients, pivot_value) = rows[rank];
+        for (row, (coefficients, value)) in rows.iter_mut().enumerate() {
+            let factor = coefficients[column];
+            if row == rank || factor == Rational::ZERO {
+                continue;
+            }
+            for (c, pivot) in coefficients.iter_mut().zip(pivot_coefficients) {
+                *c = *c - factor * pivot;
+            }
+            *value = *value - factor * pivot_value;
+        }
+        pivots.push(column);
+        rank += 1;
+    }
+
+    // The rows below the rank are all zero on the left, so their right hand side must be zero too.
+    if rows[rank..]
+        .iter()
+        .any(|&(_, value)| value != Rational::ZERO)
+    {
+        return Solutions::Inconsistent;
+    }
+    if rank < N {
+        return Solutions::Underdetermined;
+    }
+    let mut solution = [Rational::ZERO; N];
+    for (row, column) in pivots.into_iter().enumerate() {
+        solution[column] = rows[row].1;
+    }
+    Solutions::Unique(solution)
+}
+
+/// The determinant of a square matrix, which is zero if and only if the matrix is singular.
+///
+/// ```rust
+/// # use aoc::linalg::{self, Rational};
+/// let matrix = [[1, 2], [3, 4]].map(|row| row.map(Rational::from));
+/// assert_eq!(linalg::determinant(&matrix), Rational::from(-2));
+/// let singular = [[1, 2], [2, 4]].map(|row| row.map(Rational::from));
+/// assert_eq!(linalg::determinant(&singular), Rational::ZERO);
+/// # assert_eq!(linalg::determinant::<0>(&[]), Rational::ONE);
+/// # let matrix = [[0, 1, 2], [1, 0, 3], [4, -3, 8]].map(|row| row.map(Rational::from));
+/// # assert_eq!(linalg::determinant(&matrix), Rational::from(-2));
+/// ```
+pub fn determinant<const N: usize>(matrix: &[[Rational; N]; N]) -> Rational {
+    let mut rows = *matrix;
+    let mut determinant = Rational::ONE;
+    for column in 0..N {
+        let Some(pivot) = (column..N).find(|&row| rows[row][column] != Rational::ZERO) else {
+            return Rational::ZERO;
+        };
+        if pivot != column {
+            rows.swap(pivot, column);
+            determinant = -determinant;
+        }
+        let pivot_row = rows[column];
+        determinant = determinant * pivot_row[column];
+        for row in &mut rows[column + 1..] {
+            let factor = row[column] / pivot_row[column];
+            for (c, pivot) in row.iter_mut().zip(pivot_row) {
+                *c = *c - factor * pivot;
+            }
+        }
+    }
+    determinant
+}
+
+/// The whole numbers `(x, y)` in `0..=max` with `x * a + y * b = target`, for which
+/// `x * costs.0 + y * costs.1` is lowest. `None` if there are none.
+///
+/// The vectors can have any number of dimensions. When `a` and `b` are parallel, there can be
+/// many solutions, and the cheapest is found with the extended Euclidean algorithm instead of trying them all.
+/// Panics if the vectors have different lengths or a cost is negative.
+///
+/// ```rust
+/// # use aoc::linalg;
+/// // A single solution
+/// assert_eq!(linalg::cheapest_combination(&[94, 34], &[22, 67], &[8400, 5400], (3, 1), 100), Some((80, 40)));
+/// // 2 * (1, 1) + 3 * (2, 2) = 5 * (1, 1) + 0 * (2, 2) = 8 * (1, 1) and so on
+/// assert_eq!(linalg::cheapest_combination(&[1, 1], &[2, 2], &[8, 8], (3, 1), 100), Some((0, 4)));
+/// assert_eq!(linalg::cheapest_combination(&[1, 1], &[2, 2], &[8, 8], (1, 3), 100), Some((8, 0)));
+/// assert_eq!(linalg::cheapest_combination(&[1, 1], &[2, 2], &[8, 8], (1, 3), 5), Some((4, 2)));
+/// // No whole solution
+/// assert_eq!(linalg::cheapest_combination(&[2, 2], &[4, 4], &[7, 7], (3, 1), 100), None);
+/// # assert_eq!(linalg::cheapest_combination(&[1, 1], &[2, 2], &[8, 9], (3, 1), 100), None);
+/// # assert_eq!(linalg::cheapest_combination(&[0, 0], &[0, 0], &[0, 0], (3, 1), 100), Some((0, 0)));
+/// # assert_eq!(linalg::cheapest_combination(&[0, 0], &[0, 0], &[1, 0], (3, 1), 100), None);
+/// # assert_eq!(linalg::cheapest_combination(&[0, 0], &[3, 3], &[6, 6], (3, 1), 100), Some((0, 2)));
+/// # assert_eq!(linalg::cheapest_combination(&[2, 4], &[-1, -2], &[2, 4], (1, 1), 100), Some((1, 0)));
+/// # assert_eq!(linalg::cheapest_combination(&[2, 4], &[-1, -2], &[2, 4], (0, 0), 100).is_some(), true);
+/// # assert_eq!(linalg::cheapest_combination(&[1, 1], &[1, 1], &[5, 5], (0, 0), 2), None);
+/// # assert_eq!(linalg::cheapest_combination(&[2, 0], &[0, 2], &[1, 2], (3, 1), 100), None);
+/// # assert_eq!(linalg::cheapest_combination(&[1, 0], &[0, 1], &[-1, 2], (3, 1), 100), None);
+/// # assert_eq!(linalg::cheapest_combination(&[1, 0], &[0, 1], &[101, 2], (3, 1), 100), None);
+/// # assert_eq!(linalg::cheapest_combination(&[-1, 0], &[0, -1], &[-3, -2], (3, 1), 100), Some((3, 2)));
+/// # assert_eq!(linalg::cheapest_combination(&[1, 0], &[0, -1], &[-2, i64::MIN], (3, 1), i64::MAX), None);
+/// # assert_eq!(linalg::cheapest_combination(&[1, 0, 1], &[0, 1, 1], &[3, 2, 5], (3, 1), 100), Some((3, 2)));
+/// # assert_eq!(linalg::cheapest_combination(&[1, 0, 1], &[0, 1, 1], &[3, 2, 6], (3, 1), 100), None);
+/// # assert_eq!(linalg::cheapest_combination(&[94, 34], &[22, 67], &[10000000008400, 10000000005400], (3, 1), i64::MAX), None);
+/// # assert_eq!(linalg::cheapest_combination(&[1, 1], &[1, 1], &[i64::MAX, i64::MAX], (1, 2), i64::MAX), Some((i64::MAX, 0)));
+/// ```
+pub fn cheapest_combination(
+    a: &[i64],
+    b: &[i64],
+    target: &[i64],
+    costs: (i64, i64),
+    max: i64,
+) -> Option<(i64, i64)> {
+    assert!(
+        a.len() == b.len() && a.len() == target.len(),
+        "the vectors have different lengths"
+    );
+    assert!(
+        costs.0 >= 0 && costs.1 >= 0,
+        "the costs must not be negative"
+    );
+    if let ([a0, a1], [b0, b1], [t0, t1]) = (a, b, target) {
+        if let Some(presses) = cramer([*a0, *a1], [*b0, *b1], [*t0, *t1], max) {
+            return presses;
+        }
+    }
+    let equations: Vec<[Rational; 2]> = a
+        .iter()
+        .zip(b)
+        .map(|(&a, &b)| [a.into(), b.into()])
+        .collect();
+    let rhs: Vec<Rational> = target.iter().map(|&t| t.into()).collect();
+    match solve(&equations, &rhs) {
+        Solutions::Unique([x, y]) => {
+            let in_range = |n: Rational| n.to_integer().filter(|n| (0..=max as i128).contains(n));
+            Some((in_range(x)? as i64, in_range(y)? as i64))
+        }
+        Solutions::Inconsistent => None,
+        Solutions::Underdetermined => {
+            // The equations are all multiples of the same one, or all 0 = 0.
+            match (0..a.len()).find(|&i| a[i] != 0 || b[i] != 0) {
+                Some(i) => cheapest_on_line(a[i], b[i], target[i], costs, max),
+                None => (max >= 0).then_some((0, 0)),
+            }
+        }
+    }
+}
+
+/// The only solution of two equations with Cramer's rule, which is much faster than [`solve`].
+/// `None` if the determinant is 0, so that there is no single solution.
+fn cramer(a: [i64; 2], b: [i64; 2], target: [i64; 2], max: i64) -> Option<Option<(i64, i64)>> {
+    let [a, b, target] = [a, b, target].map(|v| v.map(i128::from));
+    let det = a[0] * b[1] - a[1] * b[0];
+    if det == 0 {
+        return None;
+    }
+    let x = target[0] * b[1] - target[1] * b[0];
+    let y = a[0] * target[1] - a[1] * target[0];
+    // Dividing i128s is slow, and the numbers almost always fit into an i64.
+    // Only i64::MIN / -1 does not fit the other way around.
+    let divide = |n: i128| match (i64::try_from(n), i64::try_from(det)) {
+        (Ok(n), Ok(det)) if det != -1 => (n % det == 0).then(|| (n / det) as i128),
+        _ => (n % det == 0).then(|| n / det),
+    };
+    let in_range = |n: i128| {
+        divide(n)
+            .filter(|n| (0..=max as i128).contains(n))
+            .map(|n| n as i64)
+    };
+    Some(in_range(x).zip(in_range(y)))
+}
+
+/// Like [`cheapest_combination`] for the single equation `x * p + y * q = r`, where `p` or `q` is not 0.
+fn cheapest_on_line(p: i64, q: i64, r: i64, costs: (i64, i64), max: i64) -> Option<(i64, i64)> {
+    let (gcd, u, v) = math::extended_gcd(p, q);
+    let (gcd, r) = (gcd as i128, r as i128);
+    if r % gcd != 0 {
+        return None;
+    }
+    // All solutions are x = x0 + k * dx and y = y0 - k * dy for any whole k.
+    let (x0, y0) = (u as i128 * (r / gcd), v as i128 * (r / gcd));
+    let (dx, dy) = (q as i128 / gcd, p as i128 / gcd);
+
+    // Limit k to the values where both are in `0..=max`. At least one step is not 0, so k is bounded.
+    let (mut low, mut high) = (i128::MIN, i128::MAX);
+    for (start, step) in [(x0, dx), (y0, -dy)] {
+        let max = max as i128;
+        match step.cmp(&0) {
+            Ordering::Greater => {
+                low = low.max(div_ceil(-start, step));
+                high = high.min(div_floor(max - start, step));
+            }
+            Ordering::Less => {
+                low = low.max(div_ceil(max - start, step));
+                high = high.min(div_floor(-start, step));
+            }
+            Ordering::Equal if !(0..=max).contains(&start) => return None,
+            Ordering::Equal => (),
+        }
+    }
+    if low > high {
+        return None;
+    }
+
+    // The cost changes by the same amount with every step of k, so the cheapest is at one end.
+    let slope = costs.0 as i128 * dx - costs.1 as i128 * dy;
+    let k = if slope > 0 { low } else { high };
+    Some(((x0 + k * dx) as i64, (y0 - k * dy) as i64))
+}
+
+/// Divide and round towards negative infinity.
+fn div_floor(a: i128, b: i128) -> i128 {
+    let quotient = a / b;
+    if a % b != 0 && (a < 0) != (b < 0) {
+        quotient - 1
+    } else {
+        quotient
+    }
+}
+
+/// Divide and round towards positive infinity.
+fn div_ceil(a: i128, b: i128) -> i128 {
+    -div_floor(-a, b)
+}
@@ -0,0 +1,197 @@
+//! Number theory for puzzles that repeat with a period: modular arithmetic and the Chinese remainder theorem.
+//!
+//! Everything works on `i64` and multiplies in `i128`, so intermediate results cannot overflow.
+//! Results that do not fit into an `i64` are `None` instead.
+//!
+//! A congruence `x ≡ residue (mod modulus)` is written as the pair `(residue, modulus)`.
+//! Moduli must be positive, and residues are returned in `0..modulus`.
+
+/// The greatest common divisor, which is never negative. `None` if it does not fit, which only
+/// happens for `2^63`, as in `checked_gcd(i64::MIN, 0)`.
+///
+/// Unlike [`gcd`](crate::gcd), it does not depend on the signs of the numbers.
+///
+/// ```rust
+/// # use aoc::math::checked_gcd;
+/// assert_eq!(checked_gcd(12, -8), Some(4));
+/// assert_eq!(checked_gcd(0, 0), Some(0));
+/// assert_eq!(checked_gcd(i64::MIN, i64::MIN), None);
+/// # assert_eq!(checked_gcd(i64::MIN, 6), Some(2));
+/// ```
+pub const fn checked_gcd(a: i64, b: i64) -> Option<i64> {
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    if a > i64::MAX as u64 {
+        None
+    } else {
+        Some(a as i64)
+    }
+}
+
+/// The least common multiple, which is never negative. `None` if it does not fit.
+///
+/// ```rust
+/// # use aoc::math::checked_lcm;
+/// assert_eq!(checked_lcm(4, -6), Some(12));
+/// assert_eq!(checked_lcm(0, 6), Some(0));
+/// assert_eq!(checked_lcm(i64::MAX, i64::MAX - 1), None);
+/// ```
+pub fn checked_lcm(a: i64, b: i64) -> Option<i64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    let gcd = checked_gcd(a, b)? as i128;
+    (a as i128 / gcd * b as i128).abs().try_into().ok()
+}
+
+/// The extended Euclidean algorithm: returns `(gcd, x, y)` with `a * x + b * y = gcd`.
+///
+/// The gcd is never negative, and `|x| <= |b / gcd|` and `|y| <= |a / gcd|`, so they always fit.
+/// Panics if the gcd does not fit, see [`checked_gcd`].
+///
+/// <https://en.wikipedia.org/wiki/Extended_Euclidean_algorithm>
+///
+/// ```rust
+/// # use aoc::math::extended_gcd;
+/// let (gcd, x, y) = extended_gcd(240, 46);
+/// assert_eq!(gcd, 2);
+/// assert_eq!(240 * x + 46 * y, 2);
+/// # for (a, b) in [(0, 0), (0, 5), (-7, 0), (-240, 46), (i64::MAX, i64::MIN + 1), (i64::MIN, 3)] {
+/// #     let (gcd, x, y) = extended_gcd(a, b);
+/// #     assert_eq!(a as i128 * x as i128 + b as i128 * y as i128, gcd as i128);
+/// #     assert!(gcd >= 0);
+/// # }
+/// ```
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (gcd, x, y) = extended_gcd_wide(a as i128, b as i128);
+    let fits = |n: i128| i64::try_from(n).expect("the gcd does not fit into an i64");
+    (fits(gcd), fits(x), fits(y))
+}
+
+fn extended_gcd_wide(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The number `x` in `0..modulus` with `a * x ≡ 1`, or `None` if `a` and the modulus have a common factor.
+///
+/// Panics if the modulus is not positive.
+///
+/// ```rust
+/// # use aoc::math::mod_inverse;
+/// assert_eq!(mod_inverse(3, 7), Some(5));
+/// assert_eq!(mod_inverse(-3, 7), Some(2));
+/// assert_eq!(mod_inverse(4, 6), None);
+/// # assert_eq!(mod_inverse(5, 1), Some(0));
+/// ```
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+    assert!(modulus > 0, "the modulus must be positive");
+    let (gcd, x, _) = extended_gcd_wide(a as i128, modulus as i128);
+    (gcd == 1).then(|| x.rem_euclid(modulus as i128) as i64)
+}
+
+/// `base` to the power of `exp`, in `0..modulus`. Panics if the modulus is not positive.
+///
+/// <https://en.wikipedia.org/wiki/Modular_exponentiation>
+///
+/// ```rust
+/// # use aoc::math::mod_pow;
+/// assert_eq!(mod_pow(4, 13, 497), 445);
+/// assert_eq!(mod_pow(-2, 3, 5), 2);
+/// assert_eq!(mod_pow(7, 0, 1), 0);
+/// # assert_eq!(mod_pow(i64::MAX, u64::MAX, i64::MAX - 1), 1);
+/// ```
+pub fn mod_pow(base: i64, mut exp: u64, modulus: i64) -> i64 {
+    assert!(modulus > 0, "the modulus must be positive");
+    let modulus = modulus as i128;
+    let mut base = (base as i128).rem_euclid(modulus);
+    let mut result = 1 % modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+    result as i64
+}
+
+/// Combine congruences with pairwise coprime moduli into one, whose modulus is their product.
+///
+/// `None` if two moduli have a common factor, see [`crt_general`] for those,
+/// or if the product does not fit. Panics if a modulus is not positive.
+///
+/// <https://en.wikipedia.org/wiki/Chinese_remainder_theorem>
+///
+/// ```rust
+/// # use aoc::math::crt;
+/// // x ≡ 2 (mod 3), x ≡ 3 (mod 5) and x ≡ 2 (mod 7)
+/// assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+/// assert_eq!(crt([(1, 4), (3, 6)]), None);
+/// assert_eq!(crt([]), Some((0, 1)));
+/// ```
+pub fn crt(congruences: impl IntoIterator<Item = (i64, i64)>) -> Option<(i64, i64)> {
+    congruences
+        .into_iter()
+        .try_fold((0, 1), |combined, (residue, modulus)| {
+            assert!(modulus > 0, "the modulus must be positive");
+            if checked_gcd(combined.1, modulus)? != 1 {
+                return None;
+            }
+            combine(combined, (residue, modulus))
+        })
+}
+
+/// Combine congruences whose moduli may have common factors into one, whose modulus is their lcm.
+///
+/// `None` if the congruences contradict each other, or if the lcm does not fit.
+/// Panics if a modulus is not positive.
+///
+/// ```rust
+/// # use aoc::math::crt_general;
+/// // x ≡ 1 (mod 4) and x ≡ 3 (mod 6) agree on x being odd
+/// assert_eq!(crt_general([(1, 4), (3, 6)]), Some((9, 12)));
+/// // but x ≡ 1 (mod 4) and x ≡ 2 (mod 6) do not
+/// assert_eq!(crt_general([(1, 4), (2, 6)]), None);
+/// # assert_eq!(crt_general([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+/// # assert_eq!(crt_general([(5, 10), (-5, 10)]), Some((5, 10)));
+/// # assert_eq!(crt_general([(1, i64::MAX), (0, i64::MAX - 1)]), None);
+/// ```
+pub fn crt_general(congruences: impl IntoIterator<Item = (i64, i64)>) -> Option<(i64, i64)> {
+    congruences
+        .into_iter()
+        .try_fold((0, 1), |combined, (residue, modulus)| {
+            assert!(modulus > 0, "the modulus must be positive");
+            combine(combined, (residue, modulus))
+        })
+}
+
+/// Combine two congruences into one, or `None` if they contradict each other or the lcm does not fit.
+fn combine((r1, m1): (i64, i64), (r2, m2): (i64, i64)) -> Option<(i64, i64)> {
+    let (r1, m1, r2, m2) = (r1 as i128, m1 as i128, r2 as i128, m2 as i128);
+    // x = r1 + m1 * k, so m1 * k ≡ r2 - r1 (mod m2), which can be solved if the gcd divides r2 - r1.
+    let (gcd, inverse, _) = extended_gcd_wide(m1, m2);
+    let difference = r2 - r1;
+    if difference % gcd != 0 {
+        return None;
+    }
+    let m2_reduced = m2 / gcd;
+    let k = (difference / gcd).rem_euclid(m2_reduced) * inverse.rem_euclid(m2_reduced) % m2_reduced;
+    let modulus = m1 * m2_reduced;
+    let residue = (r1 + m1 * k).rem_euclid(modulus);
+    Some((residue.try_into().ok()?, modulus.try_into().ok()?))
+}
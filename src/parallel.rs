@@ -0,0 +1,88 @@
+//! Brute force loops that run on all cores with the `parallel` feature, and on the current thread without it.
+//!
+//! A loop starts with [`iter`] instead of `iter()` or `into_iter()`. The adapters that the solutions need,
+//! like `map`, `filter`, `count` and `sum`, have the same names for rayon's parallel iterators
+//! and for std's iterators, so the rest of the loop is the same in both builds.
+//! Import [`prelude`] to have the right trait in scope.
+//!
+//! Parallel loops must not depend on the order of the items, so the answers are the same in both builds.
+
+#[cfg(feature = "parallel")]
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+/// The iterator trait whose adapters the loops use.
+pub mod prelude {
+    #[cfg(feature = "parallel")]
+    pub use rayon::iter::ParallelIterator;
+    #[cfg(not(feature = "parallel"))]
+    pub use std::iter::Iterator;
+}
+
+/// Iterate over the items, in parallel if the feature is enabled.
+///
+/// ```rust
+/// # use aoc::parallel::{self, prelude::*};
+/// let squares: u32 = parallel::iter(1..=3u32).map(|n| n * n).sum();
+/// assert_eq!(squares, 14);
+/// ```
+#[cfg(feature = "parallel")]
+pub fn iter<I: IntoParallelIterator>(items: I) -> I::Iter {
+    items.into_par_iter()
+}
+
+/// Iterate over the items, in parallel if the feature is enabled.
+///
+/// ```rust
+/// # use aoc::parallel::{self, prelude::*};
+/// let squares: u32 = parallel::iter(1..=3u32).map(|n| n * n).sum();
+/// assert_eq!(squares, 14);
+/// ```
+#[cfg(not(feature = "parallel"))]
+pub fn iter<I: IntoIterator>(items: I) -> I::IntoIter {
+    items.into_iter()
+}
+
+/// Fold the items into accumulators that start as `init()`, then combine the accumulators with `merge`.
+///
+/// Without the feature, there is only a single accumulator and `merge` is never called.
+///
+/// ```rust
+/// # use aoc::parallel;
+/// let sum = parallel::fold(1..=4u32, || 0, |sum, n| sum + n, |a, b| a + b);
+/// assert_eq!(sum, 10);
+/// ```
+#[cfg(feature = "parallel")]
+pub fn fold<I, T>(
+    items: I,
+    init: impl Fn() -> T + Sync + Send,
+    fold: impl Fn(T, I::Item) -> T + Sync + Send,
+    merge: impl Fn(T, T) -> T + Sync + Send,
+) -> T
+where
+    I: IntoParallelIterator,
+    T: Send,
+{
+    items.into_par_iter().fold(&init, fold).reduce(&init, merge)
+}
+
+/// Fold the items into accumulators that start as `init()`, then combine the accumulators with `merge`.
+///
+/// Without the feature, there is only a single accumulator and `merge` is never called.
+///
+/// ```rust
+/// # use aoc::parallel;
+/// let sum = parallel::fold(1..=4u32, || 0, |sum, n| sum + n, |a, b| a + b);
+/// assert_eq!(sum, 10);
+/// ```
+#[cfg(not(feature = "parallel"))]
+pub fn fold<I, T>(
+    items: I,
+    init: impl Fn() -> T + Sync + Send,
+    fold: impl Fn(T, I::Item) -> T + Sync + Send,
+    _merge: impl Fn(T, T) -> T + Sync + Send,
+) -> T
+where
+    I: IntoIterator,
+{
+    items.into_iter().fold(init(), fold)
+}
@@ -0,0 +1,224 @@
+//! Fallible parsing helpers that point at the offending part of the input.
+//!
+//! Parsers work on plain `&str` slices of the input. When something does not parse,
+//! they create a [`ParseError`] from the offending slice. Because that slice still points into the
+//! complete input, [`ParseError::locate`] can later turn it into a line and column.
+//!
+//! ```rust
+//! # use aoc::parse::{self, ParseError};
+//! fn parse_pair(line: &str) -> Result<(u32, u32), ParseError> {
+//!     let (a, b) = parse::split_once(line, ",")?;
+//!     Ok((parse::number(a)?, parse::number(b)?))
+//! }
+//!
+//! let input = "1,2\n3,x\n";
+//! let error = parse::run("example", input, |input| {
+//!     input.lines().map(parse_pair).collect::<Result<Vec<_>, _>>()
+//! })
+//! .unwrap_err();
+//! assert_eq!(error.line(), Some(2));
+//! assert_eq!(error.column(), Some(3));
+//! assert_eq!(error.to_string(), "example:2:3: invalid number: 'x'");
+//! ```
+
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// Maximum number of characters of offending text that are shown in an error.
+const MAX_TEXT: usize = 40;
+
+/// Something in the input did not have the expected format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    message: String,
+    text: String,
+    name: Option<String>,
+    location: Option<(usize, usize)>,
+    /// Address of the offending text, used to find it in the complete input.
+    address: usize,
+}
+
+impl ParseError {
+    /// Create an error about the given slice of the input.
+    pub fn new(text: &str, message: impl Display) -> Self {
+        Self {
+            message: message.to_string(),
+            text: text.to_string(),
+            name: None,
+            location: None,
+            address: text.as_ptr() as usize,
+        }
+    }
+
+    /// Create an error about input that ended after the given slice.
+    pub fn end_of(text: &str, message: impl Display) -> Self {
+        Self::new(&text[text.len()..], message)
+    }
+
+    /// Find the offending text in the complete input and remember its line and column.
+    ///
+    /// Does nothing if the error is not about a slice of this input.
+    pub fn locate(mut self, input: &str) -> Self {
+        let start = input.as_ptr() as usize;
+        if self.location.is_some() || self.address < start || self.address > start + input.len() {
+            return self;
+        }
+
+        let before = &input[..self.address - start];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let column = before[line_start..].chars().count() + 1;
+        self.location = Some((line, column));
+        self
+    }
+
+    /// Remember the name of the input, usually its file name.
+    pub fn named(mut self, name: impl Into<String>) -> Self {
+        self.name.get_or_insert_with(|| name.into());
+        self
+    }
+
+    /// What went wrong.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The text that could not be parsed.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The name of the input, if known.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// The line of the offending text, starting at 1. Only known after [`ParseError::locate`].
+    pub fn line(&self) -> Option<usize> {
+        self.location.map(|(line, _)| line)
+    }
+
+    /// The column of the offending text in characters, starting at 1.
+    /// Only known after [`ParseError::locate`].
+    pub fn column(&self) -> Option<usize> {
+        self.location.map(|(_, column)| column)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.name, self.location) {
+            (Some(name), Some((line, column))) => write!(f, "{name}:{line}:{column}: ")?,
+            (None, Some((line, column))) => write!(f, "{line}:{column}: ")?,
+            (Some(name), None) => write!(f, "{name}: ")?,
+            (None, None) => (),
+        }
+
+        write!(f, "{}", self.message)?;
+
+        let text = self.text.lines().next().unwrap_or_default();
+        if text.is_empty() {
+            return Ok(());
+        }
+        let shortened: String = text.chars().take(MAX_TEXT).collect();
+        let ellipsis = if shortened.len() < self.text.len() {
+            "…"
+        } else {
+            ""
+        };
+        write!(f, ": '{shortened}{ellipsis}'")
+    }
+}
+
+impl Error for ParseError {}
+
+/// Apply a solution to an input and attach the input name and position to any parse error.
+pub fn run<'a, T>(
+    name: &str,
+    input: &'a str,
+    solve: impl FnOnce(&'a str) -> Result<T, ParseError>,
+) -> Result<T, ParseError> {
+    solve(input).map_err(|e| e.locate(input).named(name))
+}
+
+/// Parse a number, or anything else that implements [`FromStr`].
+///
+/// ```rust
+/// # use aoc::parse;
+/// assert_eq!(parse::number::<i64>("-12"), Ok(-12));
+/// assert!(parse::number::<u8>("256").is_err());
+/// ```
+pub fn number<T: FromStr>(s: &str) -> Result<T, ParseError> {
+    s.parse().map_err(|_| ParseError::new(s, "invalid number"))
+}
+
+/// Split a string at the first occurrence of the delimiter.
+///
+/// ```rust
+/// # use aoc::parse;
+/// assert_eq!(parse::split_once("a: b", ": "), Ok(("a", "b")));
+/// assert_eq!(
+///     parse::split_once("a b", ": ").unwrap_err().to_string(),
+///     "expected ': ': 'a b'",
+/// );
+/// ```
+pub fn split_once<'a>(s: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(delimiter)
+        .ok_or_else(|| ParseError::new(s, format!("expected '{delimiter}'")))
+}
+
+/// Remove a prefix that must be present.
+///
+/// ```rust
+/// # use aoc::parse;
+/// assert_eq!(parse::strip_prefix("p=1,2", "p="), Ok("1,2"));
+/// assert!(parse::strip_prefix("v=1,2", "p=").is_err());
+/// ```
+pub fn strip_prefix<'a>(s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    s.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::new(s, format!("expected '{prefix}'")))
+}
+
+/// Parse a whitespace separated list of things.
+///
+/// ```rust
+/// # use aoc::parse;
+/// assert_eq!(parse::ws_separated::<u32>("1  2 3"), Ok(vec![1, 2, 3]));
+/// assert!(parse::ws_separated::<u32>("1 two 3").is_err());
+/// ```
+pub fn ws_separated<T: FromStr>(s: &str) -> Result<Vec<T>, ParseError> {
+    s.split_ascii_whitespace().map(number).collect()
+}
+
+/// Split a string into exactly `N` whitespace separated words.
+///
+/// ```rust
+/// # use aoc::parse;
+/// assert_eq!(parse::words("x00 AND y00"), Ok(["x00", "AND", "y00"]));
+/// assert!(parse::words::<3>("x00 AND").is_err());
+/// ```
+pub fn words<const N: usize>(s: &str) -> Result<[&str; N], ParseError> {
+    let words: Vec<&str> = s.split_ascii_whitespace().collect();
+    words
+        .try_into()
+        .map_err(|_| ParseError::new(s, format!("expected {N} words")))
+}
+
+/// Convert every character of a string, failing on the first character that `f` rejects.
+///
+/// ```rust
+/// # use aoc::parse;
+/// assert_eq!(parse::chars("123", |c| c.to_digit(10)), Ok(vec![1, 2, 3]));
+/// assert_eq!(
+///     parse::chars("1x3", |c| c.to_digit(10)).unwrap_err().to_string(),
+///     "unexpected character: 'x'",
+/// );
+/// ```
+pub fn chars<T>(s: &str, mut f: impl FnMut(char) -> Option<T>) -> Result<Vec<T>, ParseError> {
+    s.char_indices()
+        .map(|(i, c)| {
+            f(c).ok_or_else(|| ParseError::new(&s[i..i + c.len_utf8()], "unexpected character"))
+        })
+        .collect()
+}
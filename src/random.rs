@@ -0,0 +1,214 @@
+//! Random puzzle inputs for stress testing, see [`Solution::generate`](crate::Solution::generate).
+//!
+//! The generator is seeded explicitly and implemented here instead of taken from a crate,
+//! so that a seed keeps producing the same input across versions and platforms.
+
+use crate::Grid;
+use glam::IVec2;
+use std::ops::RangeInclusive;
+
+/// A small, seedable random number generator ([SplitMix64](https://prng.di.unimi.it/splitmix64.c)).
+///
+/// Good enough for test inputs, not for anything that needs to be unpredictable.
+///
+/// ```rust
+/// # use aoc::random::Rng;
+/// let mut a = Rng::new(7);
+/// let mut b = Rng::new(7);
+/// assert_eq!(a.next_u64(), b.next_u64());
+/// let roll = a.range(1..=6);
+/// assert!((1..=6).contains(&roll));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`. Panics if `n` is 0.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "empty range");
+        // Multiply instead of taking the remainder, which would favor small numbers.
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    /// A number in the range. Panics if the range is empty.
+    pub fn range<T>(&mut self, range: RangeInclusive<T>) -> T
+    where
+        T: Copy + TryFrom<i128> + TryInto<i128>,
+    {
+        let (Ok(start), Ok(end)) = ((*range.start()).try_into(), (*range.end()).try_into()) else {
+            unreachable!("every integer fits into i128")
+        };
+        assert!(start <= end, "empty range");
+        let offset = self.below(u64::try_from(end - start + 1).expect("range too large"));
+        let Ok(value) = T::try_from(start + offset as i128) else {
+            unreachable!("the value is in the range")
+        };
+        value
+    }
+
+    /// `true` with the given probability.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        let unit = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        unit < probability
+    }
+
+    /// A random index into a slice of the given length.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    /// A random element. Panics if the slice is empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    /// Put the items into a random order.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+
+    /// A random position in a grid of the given size.
+    pub fn position(&mut self, size: IVec2) -> IVec2 {
+        IVec2::new(self.range(0..=size.x - 1), self.range(0..=size.y - 1))
+    }
+}
+
+/// A maze without loops, where `true` is open. The grid is `2 * cells + 1` large and surrounded by walls.
+///
+/// Cells are at odd positions, so `(1, 1)` and `2 * cells - 1` are always open,
+/// and there is exactly one path between any two open tiles.
+///
+/// ```rust
+/// # use aoc::random::{self, Rng};
+/// # use glam::IVec2;
+/// let maze = random::maze(&mut Rng::new(1), IVec2::new(3, 2));
+/// assert_eq!(maze.size(), IVec2::new(7, 5));
+/// assert_eq!(maze.iter().filter(|(_, &open)| open).count(), 3 * 2 + 5);
+/// ```
+pub fn maze(rng: &mut Rng, cells: IVec2) -> Grid<bool> {
+    let mut open = Grid::new(cells * 2 + IVec2::ONE, false);
+    let start = IVec2::ONE;
+    open[start] = true;
+    let mut stack = vec![start];
+    while let Some(&cell) = stack.last() {
+        let unvisited: Vec<IVec2> = crate::DIRECTIONS4
+            .into_iter()
+            .map(|dir| cell + dir * 2)
+            .filter(|&next| open.get(next) == Some(&false))
+            .collect();
+        if unvisited.is_empty() {
+            stack.pop();
+            continue;
+        }
+        let next = *rng.choose(&unvisited);
+        open[(cell + next) / 2] = true;
+        open[next] = true;
+        stack.push(next);
+    }
+    open
+}
+
+/// Characters that [`mutate`] inserts: the ones puzzle inputs are made of, and a few that they never contain.
+const NOISE: &[char] = &[
+    '0', '1', '7', '9', '-', '+', ',', ':', '=', '|', ' ', '\n', '\n', '#', '.', '@', 'O', '[',
+    ']', '<', '>', '^', 'v', 'S', 'E', 'x', 'z', 'é', '\t', '\r',
+];
+
+/// Numbers that [`mutate`] puts in place of other numbers, at the edges of the integer types.
+const EXTREMES: &[&str] = &[
+    "0",
+    "-1",
+    "255",
+    "65536",
+    "2147483648",
+    "4294967296",
+    "9223372036854775808",
+    "99999999999999999999999",
+];
+
+/// Break a valid input in a few random places, for fuzzing parsers.
+///
+/// The mutations insert, delete, duplicate or swap characters and lines, cut the input short,
+/// and replace numbers with extreme ones, so that the result is usually almost valid.
+///
+/// ```rust
+/// # use aoc::random::{self, Rng};
+/// let mut rng = Rng::new(3);
+/// let mutated = random::mutate(&mut rng, "1,2\n3,4\n");
+/// assert_ne!(mutated, "1,2\n3,4\n");
+/// ```
+pub fn mutate(rng: &mut Rng, text: &str) -> String {
+    let mut chars: Vec<char> = text.chars().collect();
+    for _ in 0..rng.range(1..=3) {
+        let len = chars.len();
+        // A random span, usually short, with `start <= end <= len`.
+        let start = rng.below(len as u64 + 1) as usize;
+        let end = (start + rng.range(0..=8)).min(len);
+        match rng.below(8) {
+            0 => {
+                chars.drain(start..end);
+            }
+            1 => {
+                let noise = (0..rng.range(1..=3)).map(|_| *rng.choose(NOISE));
+                chars.splice(start..start, noise.collect::<Vec<_>>());
+            }
+            2 => {
+                let copy = chars[start..end].to_vec();
+                chars.splice(start..start, copy);
+            }
+            3 => chars.truncate(start),
+            4 if len > 0 => {
+                let other = rng.index(len);
+                chars.swap(start.min(len - 1), other);
+            }
+            5 => {
+                let mut lines: Vec<String> = chars
+                    .iter()
+                    .collect::<String>()
+                    .lines()
+                    .map(String::from)
+                    .collect();
+                if !lines.is_empty() {
+                    let (a, b) = (rng.index(lines.len()), rng.index(lines.len()));
+                    match rng.below(3) {
+                        0 => lines.swap(a, b),
+                        1 => drop(lines.remove(a)),
+                        _ => lines.insert(a, lines[b].clone()),
+                    }
+                }
+                chars = lines.join("\n").chars().collect();
+                chars.push('\n');
+            }
+            _ => {
+                // Replace the number around `start`, or insert one if there is none.
+                let is_digit = |i: usize| chars.get(i).is_some_and(char::is_ascii_digit);
+                let mut first = start;
+                while first > 0 && is_digit(first - 1) {
+                    first -= 1;
+                }
+                let mut last = start;
+                while is_digit(last) {
+                    last += 1;
+                }
+                chars.splice(first..last, rng.choose(EXTREMES).chars());
+            }
+        }
+    }
+    chars.into_iter().collect()
+}
@@ -0,0 +1,272 @@
+//! Connected regions of a grid, like the plots of a garden that grow the same plant.
+//!
+//! [`label`] gives every tile the number of its region in a single flood fill,
+//! and measures every region while it is at it, so everything takes linear time.
+//!
+//! ```rust
+//! # use aoc::{regions, Grid};
+//! # use glam::IVec2;
+//! let garden = Grid::parse("AAA\nABA\nAAA", |c| c);
+//! let regions = regions::label(&garden, |a, b| a == b);
+//! assert_eq!(regions.len(), 2);
+//! let outer = regions.at(IVec2::ZERO).unwrap();
+//! assert_eq!((outer.area(), outer.perimeter, outer.sides, outer.holes), (8, 16, 8, 1));
+//! ```
+
+use crate::{Grid, DIRECTIONS4};
+use glam::IVec2;
+use std::ops::Index;
+
+/// The regions of a grid, numbered in the order of their first tile row by row.
+#[derive(Debug, Clone)]
+pub struct Regions {
+    labels: Grid<usize>,
+    regions: Vec<Region>,
+}
+
+/// A group of tiles that are connected through orthogonal neighbors, and its measurements.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    /// The tiles in the order the flood fill reached them, starting with the first one row by row.
+    pub tiles: Vec<IVec2>,
+    /// The number of tile edges that border another region or the outside of the grid.
+    pub perimeter: usize,
+    /// The number of straight sides of the border, each made of one or more edges.
+    pub sides: usize,
+    /// The top left corner of the bounding box.
+    pub min: IVec2,
+    /// The bottom right corner of the bounding box, which is part of it.
+    pub max: IVec2,
+    /// The number of areas inside the region that are separated from the outside of it,
+    /// and that can contain other regions.
+    pub holes: usize,
+}
+
+impl Region {
+    pub fn area(&self) -> usize {
+        self.tiles.len()
+    }
+}
+
+impl Regions {
+    /// The number of regions.
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Region> {
+        self.regions.iter()
+    }
+
+    /// The number of the region of a tile, or `None` if it is outside the grid.
+    pub fn label(&self, pos: IVec2) -> Option<usize> {
+        self.labels.get(pos).copied()
+    }
+
+    /// The region of a tile, or `None` if it is outside the grid.
+    pub fn at(&self, pos: IVec2) -> Option<&Region> {
+        self.label(pos).map(|label| &self.regions[label])
+    }
+}
+
+impl Index<usize> for Regions {
+    type Output = Region;
+
+    fn index(&self, label: usize) -> &Region {
+        &self.regions[label]
+    }
+}
+
+/// Find the regions of neighboring tiles for which `same` is true.
+///
+/// `same` is called with neighboring tiles, and should be an equivalence, like comparing plants.
+///
+/// ```rust
+/// # use aoc::{regions, Grid};
+/// # use glam::IVec2;
+/// // Digits that differ by at most 1 are in the same region.
+/// let grid = Grid::parse("1289\n1299\n5555", |c| c.to_digit(10).unwrap());
+/// let regions = regions::label(&grid, |a, b| a.abs_diff(*b) <= 1);
+/// assert_eq!(regions.len(), 3);
+/// let first = &regions[0];
+/// assert_eq!((first.area(), first.min, first.max), (4, IVec2::ZERO, IVec2::new(1, 1)));
+/// # assert_eq!(regions[1].tiles.len() + regions[2].tiles.len(), 8);
+/// # assert_eq!((regions[2].perimeter, regions[2].sides, regions[2].holes), (10, 4, 0));
+/// ```
+pub fn label<T>(grid: &Grid<T>, mut same: impl FnMut(&T, &T) -> bool) -> Regions {
+    const UNLABELED: usize = usize::MAX;
+    let mut labels = Grid::new(grid.size(), UNLABELED);
+    let mut tiles_of_regions = Vec::new();
+    for start in grid.positions() {
+        if labels[start] != UNLABELED {
+            continue;
+        }
+        let label = tiles_of_regions.len();
+        labels[start] = label;
+        let mut tiles = vec![start];
+        let mut next = 0;
+        while let Some(&pos) = tiles.get(next) {
+            next += 1;
+            for neighbor in grid.neighbors4(pos) {
+                if labels[neighbor] == UNLABELED && same(&grid[pos], &grid[neighbor]) {
+                    labels[neighbor] = label;
+                    tiles.push(neighbor);
+                }
+            }
+        }
+        tiles_of_regions.push(tiles);
+    }
+
+    let regions = tiles_of_regions
+        .into_iter()
+        .enumerate()
+        .map(|(label, tiles)| measure(&labels, label, tiles))
+        .collect();
+    Regions { labels, regions }
+}
+
+/// The tiles that can be reached from `start` through orthogonal neighbors for which `can_enter` is true.
+///
+/// The start is always reached, unless it is outside the grid. Unlike [`label`], this only looks at one region
+/// and does not measure it, which makes it the cheaper choice for checking that two tiles are connected.
+///
+/// ```rust
+/// # use aoc::{regions, Grid};
+/// # use glam::IVec2;
+/// let maze = Grid::parse("S.#\n#.#\n#.E", |c| c);
+/// let reached = regions::flood_fill(&maze, IVec2::ZERO, |&c| c != '#');
+/// assert!(reached[IVec2::new(2, 2)]);
+/// assert!(!reached[IVec2::new(2, 0)]);
+/// # assert!(regions::flood_fill(&maze, IVec2::new(2, 0), |&c| c == '.')[IVec2::new(2, 0)]);
+/// # assert!(!regions::flood_fill(&maze, IVec2::new(3, 0), |_| true)[IVec2::ZERO]);
+/// ```
+pub fn flood_fill<T>(
+    grid: &Grid<T>,
+    start: IVec2,
+    mut can_enter: impl FnMut(&T) -> bool,
+) -> Grid<bool> {
+    let mut reached = Grid::new(grid.size(), false);
+    if !grid.contains(start) {
+        return reached;
+    }
+    reached[start] = true;
+    let mut todo = vec![start];
+    while let Some(pos) = todo.pop() {
+        for neighbor in grid.neighbors4(pos) {
+            if !reached[neighbor] && can_enter(&grid[neighbor]) {
+                reached[neighbor] = true;
+                todo.push(neighbor);
+            }
+        }
+    }
+    reached
+}
+
+fn measure(labels: &Grid<usize>, label: usize, tiles: Vec<IVec2>) -> Region {
+    let inside = |pos: IVec2| labels.get(pos) == Some(&label);
+
+    let mut perimeter = 0;
+    let mut sides = 0;
+    let mut corner_points = 0;
+    let (mut min, mut max) = (tiles[0], tiles[0]);
+    for &tile in &tiles {
+        min = min.min(tile);
+        max = max.max(tile);
+        for dir in DIRECTIONS4 {
+            if inside(tile + dir) {
+                continue;
+            }
+            perimeter += 1;
+            // Every side has one edge at its end, where the next tile along it has no edge in the same direction.
+            let along = tile + dir.perp();
+            if !inside(along) || inside(along + dir) {
+                sides += 1;
+            }
+        }
+        // Count every corner point of the region once, from the first of the tiles around it.
+        for corner in [IVec2::ZERO, IVec2::X, IVec2::Y, IVec2::ONE] {
+            let point = tile + corner;
+            let around = [
+                point - IVec2::ONE,
+                point - IVec2::Y,
+                point - IVec2::X,
+                point,
+            ];
+            if around.into_iter().find(|&pos| inside(pos)) == Some(tile) {
+                corner_points += 1;
+            }
+        }
+    }
+
+    // The Euler characteristic of the region, corners - edges + tiles, is 1 minus the number of holes.
+    // Inner edges belong to two tiles and outer edges to one, so there are (4 * area + perimeter) / 2 edges.
+    let edges = (4 * tiles.len() + perimeter) / 2;
+    let holes = 1 + edges - corner_points - tiles.len();
+    Region {
+        tiles,
+        perimeter,
+        sides,
+        min,
+        max,
+        holes,
+    }
+}
+
+/// The area, perimeter, sides and holes of the region of a tile.
+#[cfg(test)]
+fn measurements(regions: &Regions, x: i32, y: i32) -> (usize, usize, usize, usize) {
+    let region = regions.at(IVec2::new(x, y)).unwrap();
+    (region.area(), region.perimeter, region.sides, region.holes)
+}
+
+#[test]
+fn test_holes() {
+    let garden = Grid::parse("AAAAA\nABABA\nAAAAA", |c| c);
+    let regions = label(&garden, |a, b| a == b);
+    assert_eq!(regions.len(), 3);
+    // Both plants inside add their edges to the perimeter and their sides.
+    assert_eq!(measurements(&regions, 0, 0), (13, 24, 12, 2));
+    assert_eq!(measurements(&regions, 1, 1), (1, 4, 4, 0));
+    assert_ne!(
+        regions.label(IVec2::new(1, 1)),
+        regions.label(IVec2::new(3, 1))
+    );
+}
+
+#[test]
+fn test_diagonal_neighbors() {
+    // Tiles that only touch at a corner are separate regions.
+    let garden = Grid::parse("AB\nBA", |c| c);
+    let regions = label(&garden, |a, b| a == b);
+    assert_eq!(regions.len(), 4);
+    assert!(regions.iter().all(|region| region.area() == 1));
+
+    // The A region touches itself at a corner between the two B regions, which are separate holes.
+    let garden = Grid::parse("AAAAAA\nAAABBA\nAAABBA\nABBAAA\nABBAAA\nAAAAAA", |c| c);
+    let regions = label(&garden, |a, b| a == b);
+    assert_eq!(regions.len(), 3);
+    assert_eq!(measurements(&regions, 0, 0), (28, 40, 12, 2));
+    assert_eq!(measurements(&regions, 3, 1), (4, 8, 4, 0));
+    assert_eq!(measurements(&regions, 1, 3), (4, 8, 4, 0));
+}
+
+#[test]
+fn test_grid_border() {
+    // The B region reaches the bottom edge of the grid, so it is not a hole of the A region around it.
+    let garden = Grid::parse("AAA\nABA\nABA", |c| c);
+    let regions = label(&garden, |a, b| a == b);
+    assert_eq!(measurements(&regions, 0, 0), (7, 16, 8, 0));
+    assert_eq!(measurements(&regions, 1, 2), (2, 6, 4, 0));
+    let b = &regions[1];
+    assert_eq!((b.min, b.max), (IVec2::new(1, 1), IVec2::new(1, 2)));
+    assert_eq!(regions.at(IVec2::new(3, 0)), None);
+    assert_eq!(regions.label(IVec2::new(-1, 0)), None);
+
+    // A single region that fills the grid.
+    let regions = label(&Grid::new(IVec2::new(4, 2), 0), |a, b| a == b);
+    assert_eq!(measurements(&regions, 3, 1), (8, 12, 4, 0));
+}
@@ -0,0 +1,353 @@
+//! Shortest path searches over arbitrary states.
+//!
+//! The graph is never built up front. Instead every search takes a start state and a closure
+//! that returns the successors of a state, so the state can be anything hashable,
+//! like a position, a position with a direction or a whole puzzle configuration.
+
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The result of exploring everything reachable from a start state.
+///
+/// Besides the distance to every state, this remembers all predecessors that lie on a shortest path,
+/// which forms a directed acyclic graph of all shortest paths.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<S, C> {
+    start: S,
+    distances: HashMap<S, C>,
+    previous: HashMap<S, Vec<S>>,
+}
+
+impl<S, C> ShortestPaths<S, C>
+where
+    S: Eq + Hash + Clone,
+    C: Copy,
+{
+    /// The state the search started from.
+    pub fn start(&self) -> &S {
+        &self.start
+    }
+
+    /// The cost of the cheapest path to the state, or `None` if it is not reachable.
+    pub fn distance(&self, state: &S) -> Option<C> {
+        self.distances.get(state).copied()
+    }
+
+    /// All reachable states and their distance, in no particular order.
+    pub fn distances(&self) -> impl Iterator<Item = (&S, C)> {
+        self.distances.iter().map(|(state, &cost)| (state, cost))
+    }
+
+    /// The states that directly precede the given state on some shortest path.
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.previous.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    /// Return one shortest path from the start to the target, including both.
+    ///
+    /// ```rust
+    /// # use aoc::search::bfs;
+    /// let paths = bfs(1, |&n| [n + 1, n * 2].into_iter().filter(|&n| n <= 10));
+    /// assert_eq!(paths.path(&10), Some(vec![1, 2, 4, 5, 10]));
+    /// # assert_eq!(paths.path(&1), Some(vec![1]));
+    /// # assert_eq!(paths.path(&11), None);
+    /// ```
+    pub fn path(&self, target: &S) -> Option<Vec<S>> {
+        if !self.distances.contains_key(target) {
+            return None;
+        }
+
+        let mut path = vec![target.clone()];
+        while let Some(previous) = self.predecessors(path.last().unwrap()).first() {
+            path.push(previous.clone());
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// Return every shortest path from the start to the target, including both.
+    ///
+    /// The number of paths can grow exponentially, so only use this on small graphs.
+    /// Zero cost steps can make states precede each other, so paths skip the states they already contain.
+    ///
+    /// ```rust
+    /// # use aoc::search::bfs;
+    /// // Walk a 2x2 square from one corner to the opposite one.
+    /// let paths = bfs((0, 0), |&(x, y)| [(x + 1, y), (x, y + 1)].into_iter().filter(|&(x, y)| x <= 1 && y <= 1));
+    /// let mut all = paths.all_paths(&(1, 1));
+    /// all.sort();
+    /// assert_eq!(all, [vec![(0, 0), (0, 1), (1, 1)], vec![(0, 0), (1, 0), (1, 1)]]);
+    /// # // 1 and 2 reach each other for free, so each is a predecessor of the other.
+    /// # let paths = aoc::search::dijkstra(0, |&n: &u8| match n {
+    /// #     0 => vec![(1, 1), (2, 1)],
+    /// #     1 => vec![(2, 0), (3, 1)],
+    /// #     2 => vec![(1, 0)],
+    /// #     _ => vec![],
+    /// # });
+    /// # let mut all = paths.all_paths(&3);
+    /// # all.sort();
+    /// # assert_eq!(all, [vec![0, 1, 3], vec![0, 2, 1, 3]]);
+    /// ```
+    pub fn all_paths(&self, target: &S) -> Vec<Vec<S>> {
+        let mut paths = Vec::new();
+        if self.distances.contains_key(target) {
+            self.extend_paths(&mut vec![target.clone()], &mut paths);
+        }
+        paths
+    }
+
+    /// Add every way to extend a path, which is built backwards from the target, back to the start.
+    fn extend_paths(&self, path: &mut Vec<S>, paths: &mut Vec<Vec<S>>) {
+        let previous = self.predecessors(path.last().unwrap());
+        if previous.is_empty() {
+            paths.push(path.iter().rev().cloned().collect());
+            return;
+        }
+
+        for state in previous {
+            if !path.contains(state) {
+                path.push(state.clone());
+                self.extend_paths(path, paths);
+                path.pop();
+            }
+        }
+    }
+
+    /// Return every state that lies on at least one shortest path to any of the targets.
+    ///
+    /// ```rust
+    /// # use aoc::search::bfs;
+    /// let paths = bfs((0, 0), |&(x, y)| [(x + 1, y), (x, y + 1)].into_iter().filter(|&(x, y)| x <= 1 && y <= 1));
+    /// assert_eq!(paths.on_shortest_paths([(1, 1)]).len(), 4);
+    /// assert_eq!(paths.on_shortest_paths([(1, 0)]).len(), 2);
+    /// ```
+    pub fn on_shortest_paths(&self, targets: impl IntoIterator<Item = S>) -> HashSet<S> {
+        let mut seen = HashSet::new();
+        let mut todo: Vec<S> = targets
+            .into_iter()
+            .filter(|target| self.distances.contains_key(target))
+            .collect();
+
+        while let Some(state) = todo.pop() {
+            if !seen.insert(state.clone()) {
+                continue;
+            }
+            todo.extend(self.predecessors(&state).iter().cloned());
+        }
+
+        seen
+    }
+}
+
+/// Find the cheapest paths from the start to every reachable state with
+/// [Dijkstra's algorithm](https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm).
+///
+/// `successors` returns the neighbors of a state together with the cost to move there.
+/// Costs must not be negative. With zero costs, states can precede each other in [`ShortestPaths`],
+/// which [`ShortestPaths::all_paths`] and [`ShortestPaths::on_shortest_paths`] take care of.
+///
+/// ```rust
+/// # use aoc::search::dijkstra;
+/// // Going 0 -> 1 -> 2 is cheaper than going 0 -> 2 directly.
+/// let paths = dijkstra(0, |&n| match n {
+///     0 => vec![(1, 1), (2, 5)],
+///     1 => vec![(2, 1)],
+///     _ => vec![],
+/// });
+/// assert_eq!(paths.distance(&2), Some(2));
+/// assert_eq!(paths.path(&2), Some(vec![0, 1, 2]));
+/// ```
+pub fn dijkstra<S, C, I>(start: S, mut successors: impl FnMut(&S) -> I) -> ShortestPaths<S, C>
+where
+    S: Eq + Hash + Clone,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut distances = HashMap::from([(start.clone(), C::default())]);
+    let mut previous: HashMap<S, Vec<S>> = HashMap::new();
+    let mut queue = BinaryHeap::from([Queued {
+        cost: C::default(),
+        state: start.clone(),
+    }]);
+
+    while let Some(Queued { cost, state }) = queue.pop() {
+        if cost > distances[&state] {
+            // Already found a better way here
+            continue;
+        }
+
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            match distances.entry(next.clone()) {
+                Entry::Vacant(entry) => {
+                    entry.insert(next_cost);
+                }
+                Entry::Occupied(mut entry) => match next_cost.cmp(entry.get()) {
+                    Ordering::Less => {
+                        entry.insert(next_cost);
+                    }
+                    Ordering::Equal => {
+                        previous.entry(next).or_default().push(state.clone());
+                        continue;
+                    }
+                    Ordering::Greater => continue,
+                },
+            }
+            previous.insert(next.clone(), vec![state.clone()]);
+            queue.push(Queued {
+                cost: next_cost,
+                state: next,
+            });
+        }
+    }
+
+    // The start can only end up with predecessors through zero cost cycles.
+    previous.remove(&start);
+
+    ShortestPaths {
+        start,
+        distances,
+        previous,
+    }
+}
+
+/// Find the cheapest path from the start to a goal with
+/// [A*](https://en.wikipedia.org/wiki/A*_search_algorithm).
+///
+/// `heuristic` estimates the remaining cost to the goal and must never overestimate it.
+/// Returns the path including start and goal, and its cost.
+///
+/// ```rust
+/// # use aoc::search::astar;
+/// // Walk along a line from 0 to 7, either by 1 or by 3 steps.
+/// let (path, cost) = astar(
+///     0i32,
+///     |&n| [(n + 1, 1), (n + 3, 1)],
+///     |&n| (7 - n).max(0) / 3,
+///     |&n| n == 7,
+/// )
+/// .unwrap();
+/// assert_eq!(cost, 3);
+/// assert_eq!(path.len(), 4);
+/// ```
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<(Vec<S>, C)>
+where
+    S: Eq + Hash + Clone,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut distances = HashMap::from([(start.clone(), C::default())]);
+    let mut previous: HashMap<S, S> = HashMap::new();
+    let mut queue = BinaryHeap::from([Queued {
+        cost: heuristic(&start),
+        state: start,
+    }]);
+
+    while let Some(Queued { state, .. }) = queue.pop() {
+        let cost = distances[&state];
+
+        if is_goal(&state) {
+            let mut path = vec![state];
+            while let Some(p) = previous.get(path.last().unwrap()) {
+                path.push(p.clone());
+            }
+            path.reverse();
+            return Some((path, cost));
+        }
+
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            if distances.get(&next).is_some_and(|&best| next_cost >= best) {
+                continue;
+            }
+            distances.insert(next.clone(), next_cost);
+            previous.insert(next.clone(), state.clone());
+            queue.push(Queued {
+                cost: next_cost + heuristic(&next),
+                state: next,
+            });
+        }
+    }
+
+    None
+}
+
+/// Find the shortest paths from the start to every reachable state with a
+/// [breadth-first search](https://en.wikipedia.org/wiki/Breadth-first_search).
+///
+/// Every step costs 1.
+///
+/// ```rust
+/// # use aoc::search::bfs;
+/// let paths = bfs(1, |&n| [n + 1, n * 2].into_iter().filter(|&n| n <= 10));
+/// assert_eq!(paths.distance(&10), Some(4));
+/// assert_eq!(paths.distance(&8), Some(3));
+/// ```
+pub fn bfs<S, I>(start: S, mut successors: impl FnMut(&S) -> I) -> ShortestPaths<S, usize>
+where
+    S: Eq + Hash + Clone,
+    I: IntoIterator<Item = S>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut previous: HashMap<S, Vec<S>> = HashMap::new();
+    let mut queue = VecDeque::from([start.clone()]);
+
+    while let Some(state) = queue.pop_front() {
+        let next_distance = distances[&state] + 1;
+        for next in successors(&state) {
+            match distances.get(&next) {
+                None => {
+                    distances.insert(next.clone(), next_distance);
+                    previous.insert(next.clone(), vec![state.clone()]);
+                    queue.push_back(next);
+                }
+                Some(&d) if d == next_distance => {
+                    previous.entry(next).or_default().push(state.clone());
+                }
+                Some(_) => (),
+            }
+        }
+    }
+
+    ShortestPaths {
+        start,
+        distances,
+        previous,
+    }
+}
+
+/// An entry in the priority queue. Ordered by cost only, cheapest first,
+/// so that states do not need to implement [`Ord`].
+struct Queued<S, C> {
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> Ord for Queued<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost.cmp(&self.cost)
+    }
+}
+
+impl<S, C: Ord> PartialOrd for Queued<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> PartialEq for Queued<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cost == other.cost
+    }
+}
+
+impl<S, C: Ord> Eq for Queued<S, C> {}
@@ -0,0 +1,280 @@
+//! A common interface for the puzzles of every day, so that a single runner can solve all of them.
+
+use crate::artifact;
+use crate::input::Input;
+use crate::parse::ParseError;
+use crate::random::Rng;
+use std::any;
+use std::fmt::{self, Display};
+use std::hint::black_box;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// The solution to the puzzle of one day.
+///
+/// Parsing is the only step that may fail. Both parts work on the same parsed input.
+/// A part that finds no answer for an input returns an empty one, which is never recorded or submitted.
+pub trait Solution {
+    /// The puzzle input after parsing. May borrow from the input text.
+    type Parsed<'a>;
+
+    /// Parse and validate the puzzle input.
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError>;
+
+    /// Solve the first part.
+    fn part1(input: &Self::Parsed<'_>) -> impl Display;
+
+    /// Solve the second part.
+    fn part2(input: &Self::Parsed<'_>) -> impl Display;
+
+    /// The examples of the puzzle with their expected answers. `cargo test` checks every one of them.
+    const EXAMPLES: &'static [Example] = &[];
+
+    /// Generate a random input that is valid for the puzzle, including its guarantees, like a solution existing.
+    ///
+    /// `size` is roughly the number of lines or the side of the grid. Days clamp it to what their format allows.
+    /// Returns `None` if the day has no generator.
+    fn generate(_rng: &mut Rng, _size: usize) -> Option<String> {
+        None
+    }
+
+    /// Solve a part in a slow but obviously correct way, to check the clever solution on generated inputs.
+    ///
+    /// Returns `None` for parts without a reference solution.
+    fn reference(_input: &Self::Parsed<'_>, _part: Part) -> Option<String> {
+        None
+    }
+}
+
+/// An example input from the puzzle text together with the expected answer for one part.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Example {
+    /// The part that is checked.
+    pub part: Part,
+    /// The name of the file in the input directory, like `"05-test"`.
+    pub file: &'static str,
+    /// The expected answer, compared to the string representation of the actual answer.
+    pub expected: &'static str,
+}
+
+impl Example {
+    pub const fn new(part: Part, file: &'static str, expected: &'static str) -> Self {
+        Self {
+            part,
+            file,
+            expected,
+        }
+    }
+}
+
+/// One of the two parts of a puzzle.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// Both parts, in order.
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    /// `1` or `2`.
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// Parse `1` or `2`.
+///
+/// ```rust
+/// # use aoc::Part;
+/// assert_eq!("2".parse(), Ok(Part::Two));
+/// assert!("3".parse::<Part>().is_err());
+/// ```
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            other => Err(format!("part must be 1 or 2, got '{other}'")),
+        }
+    }
+}
+
+/// How long each phase of solving a day took.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+/// The answer to one part of a puzzle.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Answer {
+    pub part: Part,
+    /// The answer as it is displayed.
+    pub value: String,
+    /// The name of the type that the part returned, like `usize` or `String`.
+    pub type_name: &'static str,
+    /// How long solving the part took.
+    pub time: Duration,
+    /// The files of the artifacts that the part saved, see [`artifact`](crate::artifact).
+    pub artifacts: Vec<PathBuf>,
+}
+
+/// The answers to the parts of a day, together with how long parsing took.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Solved {
+    pub parse: Duration,
+    pub answers: Vec<Answer>,
+}
+
+/// A [`Solution`] registered for a day, with the types erased so that all days fit into one list.
+#[derive(Copy, Clone)]
+pub struct Day {
+    year: u16,
+    number: u8,
+    run: fn(&Input, &[Part]) -> Result<Solved, ParseError>,
+    time: fn(&Input) -> Result<Timings, ParseError>,
+    examples: &'static [Example],
+    generate: fn(&mut Rng, usize) -> Option<String>,
+    reference: fn(&Input, &[Part]) -> Result<References, ParseError>,
+}
+
+/// The answers of the reference solution for some parts, `None` for parts without one.
+type References = Vec<Option<String>>;
+
+impl Day {
+    /// Register the solution for the given day of a year.
+    pub const fn new<S: Solution>(year: u16, number: u8) -> Self {
+        Self {
+            year,
+            number,
+            run: run::<S>,
+            time: time::<S>,
+            examples: S::EXAMPLES,
+            generate: S::generate,
+            reference: reference::<S>,
+        }
+    }
+
+    /// The year of the puzzle.
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// The day of the month, starting at 1.
+    pub fn number(&self) -> u8 {
+        self.number
+    }
+
+    /// Parse the input once and solve the given parts. Returns the answers in the same order.
+    pub fn solve(&self, input: &Input, parts: &[Part]) -> Result<Vec<String>, ParseError> {
+        let solved = self.run(input, parts)?;
+        Ok(solved
+            .answers
+            .into_iter()
+            .map(|answer| answer.value)
+            .collect())
+    }
+
+    /// Like [`Day::solve`], but also return the type of each answer and how long each step took.
+    pub fn run(&self, input: &Input, parts: &[Part]) -> Result<Solved, ParseError> {
+        (self.run)(input, parts)
+    }
+
+    /// Solve both parts once and measure how long parsing and each part took.
+    pub fn time(&self, input: &Input) -> Result<Timings, ParseError> {
+        (self.time)(input)
+    }
+
+    /// The examples of the puzzle with their expected answers.
+    pub fn examples(&self) -> &'static [Example] {
+        self.examples
+    }
+
+    /// Generate a random input, the same one for the same seed and size. See [`Solution::generate`].
+    pub fn generate(&self, seed: u64, size: usize) -> Option<String> {
+        (self.generate)(&mut Rng::new(seed), size)
+    }
+
+    /// Like [`Day::solve`], but with the reference solution. Parts without one are `None`, see [`Solution::reference`].
+    pub fn reference(&self, input: &Input, parts: &[Part]) -> Result<References, ParseError> {
+        (self.reference)(input, parts)
+    }
+}
+
+fn run<S: Solution>(input: &Input, parts: &[Part]) -> Result<Solved, ParseError> {
+    let start = Instant::now();
+    let parsed = input.solve(S::parse)?;
+    let parse = start.elapsed();
+
+    let answers = parts
+        .iter()
+        .map(|&part| {
+            artifact::take_saved();
+            let start = Instant::now();
+            match part {
+                Part::One => answer(part, S::part1(&parsed), start),
+                Part::Two => answer(part, S::part2(&parsed), start),
+            }
+        })
+        .collect();
+    Ok(Solved { parse, answers })
+}
+
+fn answer(part: Part, value: impl Display, start: Instant) -> Answer {
+    let value_string = value.to_string();
+    let time = start.elapsed();
+    // Only keep the last segment of paths like `alloc::string::String`.
+    let type_name = any::type_name_of_val(&value);
+    let type_name = type_name.rsplit("::").next().unwrap_or(type_name);
+    Answer {
+        part,
+        value: value_string,
+        type_name,
+        time,
+        artifacts: artifact::take_saved(),
+    }
+}
+
+fn reference<S: Solution>(input: &Input, parts: &[Part]) -> Result<References, ParseError> {
+    let parsed = input.solve(S::parse)?;
+    Ok(parts
+        .iter()
+        .map(|&part| S::reference(&parsed, part))
+        .collect())
+}
+
+fn time<S: Solution>(input: &Input) -> Result<Timings, ParseError> {
+    let start = Instant::now();
+    let parsed = input.solve(S::parse)?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    black_box(S::part1(black_box(&parsed)).to_string());
+    let part1 = start.elapsed();
+
+    let start = Instant::now();
+    black_box(S::part2(black_box(&parsed)).to_string());
+    let part2 = start.elapsed();
+
+    Ok(Timings {
+        parse,
+        part1,
+        part2,
+    })
+}
@@ -0,0 +1,247 @@
+//! Understanding the responses to submitted answers and remembering wrong guesses.
+//!
+//! Wrong guesses are logged next to the input, in `input/2024/05-guesses` for day 5 of 2024,
+//! with one guess per line:
+//!
+//! ```text
+//! 1: 1234 too high
+//! 1: 12 too low
+//! 2: 99 wrong
+//! ```
+
+use crate::input::{self, InputError};
+use crate::parse::{self, ParseError};
+use crate::Part;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// What the website said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Response {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint about the direction.
+    Wrong,
+    /// An answer was submitted too recently. Try again after this time.
+    Wait(Duration),
+    /// The part is already solved or not unlocked yet.
+    AlreadySolved,
+}
+
+impl Response {
+    /// Read the response from the page that the website returns after submitting.
+    ///
+    /// ```rust
+    /// # use aoc::submit::Response;
+    /// # use std::time::Duration;
+    /// let page = "<article><p>That's not the right answer; your answer is too low.</p></article>";
+    /// assert_eq!(Response::parse(page), Some(Response::TooLow));
+    /// let page = "<article><p>You gave an answer too recently; you have to wait after submitting \
+    ///             an answer before trying again.  You have 37s left to wait.</p></article>";
+    /// assert_eq!(Response::parse(page), Some(Response::Wait(Duration::from_secs(37))));
+    /// assert_eq!(Response::parse("<html>Something else</html>"), None);
+    /// ```
+    pub fn parse(page: &str) -> Option<Self> {
+        let response = if page.contains("That's the right answer") {
+            Self::Correct
+        } else if page.contains("your answer is too high") {
+            Self::TooHigh
+        } else if page.contains("your answer is too low") {
+            Self::TooLow
+        } else if page.contains("That's not the right answer") {
+            Self::Wrong
+        } else if page.contains("You gave an answer too recently") {
+            Self::Wait(wait_time(page))
+        } else if page.contains("Did you already complete it") {
+            Self::AlreadySolved
+        } else {
+            return None;
+        };
+        Some(response)
+    }
+}
+
+impl Display for Response {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::TooHigh => write!(f, "too high"),
+            Self::TooLow => write!(f, "too low"),
+            Self::Wrong => write!(f, "wrong"),
+            Self::Wait(time) => write!(f, "wait {}s", time.as_secs()),
+            Self::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+/// Read the time in the last sentence like `You have 1m 5s left to wait`. Defaults to a minute.
+fn wait_time(page: &str) -> Duration {
+    let page = page.to_ascii_lowercase();
+    let Some(time) = page
+        .rsplit_once("you have ")
+        .and_then(|(_, rest)| rest.split_once(" left to wait"))
+        .map(|(time, _)| time)
+    else {
+        return Duration::from_secs(60);
+    };
+    let seconds = time
+        .split_whitespace()
+        .filter_map(|part| {
+            let digits = part
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(part.len());
+            let (number, unit) = part.split_at(digits);
+            let number: u64 = number.parse().ok()?;
+            Some(match unit {
+                "h" => number * 3600,
+                "m" => number * 60,
+                _ => number,
+            })
+        })
+        .sum();
+    Duration::from_secs(seconds)
+}
+
+#[test]
+fn test_wait_time() {
+    let page = "You gave an answer too recently; you have to wait after submitting an answer \
+                before trying again. You have 37s left to wait.";
+    assert_eq!(wait_time(page), Duration::from_secs(37));
+    let page = "You have to wait. YOU HAVE 2m 5s LEFT TO WAIT.";
+    assert_eq!(wait_time(page), Duration::from_secs(125));
+    assert_eq!(
+        wait_time("You have about 3m left to wait"),
+        Duration::from_secs(180)
+    );
+    assert_eq!(
+        wait_time("You have 42 left to wait"),
+        Duration::from_secs(42)
+    );
+    assert_eq!(wait_time("You have to wait."), Duration::from_secs(60));
+}
+
+/// The wrong answers that were submitted for one day.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Guesses {
+    guesses: Vec<(Part, String, Response)>,
+}
+
+impl Guesses {
+    /// Parse the contents of a guesses file.
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let mut guesses = Self::default();
+        for line in text.lines().filter(|line| !line.trim().is_empty()) {
+            let (part, rest) = parse::split_once(line, ": ")?;
+            let part = part.parse().map_err(|e: String| ParseError::new(part, e))?;
+            let (answer, response) = parse::split_once(rest, " ")?;
+            let response = match response {
+                "too high" => Response::TooHigh,
+                "too low" => Response::TooLow,
+                "wrong" => Response::Wrong,
+                _ => {
+                    return Err(ParseError::new(
+                        response,
+                        "expected 'too high', 'too low' or 'wrong'",
+                    ))
+                }
+            };
+            guesses.add(part, answer, response);
+        }
+        Ok(guesses)
+    }
+
+    /// Remember a wrong guess.
+    pub fn add(&mut self, part: Part, answer: impl Into<String>, response: Response) {
+        self.guesses.push((part, answer.into(), response));
+    }
+
+    /// Explain why an answer is known to be wrong, or `None` if it might be right.
+    ///
+    /// Besides answers that were submitted before, numbers that are beyond a previous too high or too low guess are wrong as well.
+    ///
+    /// ```rust
+    /// # use aoc::submit::Guesses;
+    /// # use aoc::Part;
+    /// let guesses = Guesses::parse("1: 100 too high\n1: 50 too low\n").unwrap();
+    /// assert_eq!(guesses.check(Part::One, "100").unwrap(), "100 was already submitted and is too high");
+    /// assert_eq!(guesses.check(Part::One, "120").unwrap(), "100 was already too high");
+    /// assert_eq!(guesses.check(Part::One, "75"), None);
+    /// assert_eq!(guesses.check(Part::Two, "100"), None);
+    /// ```
+    pub fn check(&self, part: Part, answer: &str) -> Option<String> {
+        let guesses = self.guesses.iter().filter(|(p, _, _)| *p == part);
+        if let Some((_, _, response)) = guesses.clone().find(|(_, a, _)| a == answer) {
+            return Some(format!("{answer} was already submitted and is {response}"));
+        }
+
+        let answer: i64 = answer.parse().ok()?;
+        guesses.into_iter().find_map(|(_, guess, response)| {
+            let guess: i64 = guess.parse().ok()?;
+            match response {
+                Response::TooHigh if answer >= guess => {
+                    Some(format!("{guess} was already too high"))
+                }
+                Response::TooLow if answer <= guess => Some(format!("{guess} was already too low")),
+                _ => None,
+            }
+        })
+    }
+}
+
+impl Display for Guesses {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (part, answer, response) in &self.guesses {
+            writeln!(f, "{part}: {answer} {response}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The guesses file of a day could not be loaded.
+#[derive(Debug)]
+pub enum GuessesError {
+    Input(InputError),
+    Parse(ParseError),
+}
+
+impl Display for GuessesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Input(e) => write!(f, "{e}"),
+            Self::Parse(e) => write!(f, "invalid guesses: {e}"),
+        }
+    }
+}
+
+impl Error for GuessesError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Input(e) => Some(e),
+            Self::Parse(e) => Some(e),
+        }
+    }
+}
+
+/// The guesses file of a day.
+pub fn path(year: u16, day: u8) -> PathBuf {
+    input::year_dir(year).join(format!("{day:02}-guesses"))
+}
+
+/// Load the wrong guesses of a day. A missing file has none.
+pub fn load(year: u16, day: u8) -> Result<Guesses, GuessesError> {
+    match input::path(path(year, day)) {
+        Ok(input) => input.solve(Guesses::parse).map_err(GuessesError::Parse),
+        Err(InputError::Missing(_)) => Ok(Guesses::default()),
+        Err(e) => Err(GuessesError::Input(e)),
+    }
+}
+
+/// Write the wrong guesses of a day.
+pub fn save(year: u16, day: u8, guesses: &Guesses) -> io::Result<()> {
+    fs::write(path(year, day), guesses.to_string())
+}
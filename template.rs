@@ -1,18 +1,27 @@
-use aoc::*;
+//! <https://adventofcode.com/YEAR/day/NUMBER>
 
-const INPUT: &str = include_str!("../../input/DAY");
+use crate::*;
+use std::fmt::Display;
 
-fn main() {
-    assert_example!(part1, "DAY-test", 0);
-    println!("Part 1: {}", part1(INPUT));
-    assert_example!(part2, "DAY-test", 0);
-    println!("Part 2: {}", part2(INPUT));
-}
+pub struct DayDAY;
 
-fn part1(input: &str) -> usize {
-    0
-}
+impl Solution for DayDAY {
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Parsed<'_>) -> impl Display {
+        0
+    }
+
+    fn part2(input: &Self::Parsed<'_>) -> impl Display {
+        0
+    }
 
-fn part2(input: &str) -> usize {
-    0
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(Part::One, "DAY-test", "0"),
+        Example::new(Part::Two, "DAY-test", "0"),
+    ];
 }
@@ -0,0 +1,75 @@
+//! Checks the examples of every day against their expected answers.
+//!
+//! Every entry in the example table of a day becomes one test, named like `y2024::day05::part2::05-test`.
+//! Examples whose input file does not exist are reported as ignored instead of failing.
+//!
+//! Days with an input generator also get a test named like `y2024::day05::generated`,
+//! which checks that random inputs of several sizes parse, and one named like `y2024::day05::fuzz`,
+//! which checks that broken versions of them are rejected without a panic. `aoc fuzz` tries many more.
+
+use aoc::input::{self, Input};
+use aoc::random::{self, Rng};
+use aoc::{days, Day, Example};
+use libtest_mimic::{Arguments, Failed, Trial};
+use std::panic::{self, AssertUnwindSafe};
+
+fn main() {
+    let args = Arguments::from_args();
+    let mut tests = Vec::new();
+    for day in days::all() {
+        for &example in day.examples() {
+            let name = format!(
+                "y{}::day{:02}::part{}::{}",
+                day.year(),
+                day.number(),
+                example.part,
+                example.file
+            );
+            let missing = !input::year_dir(day.year()).join(example.file).exists();
+            let test = Trial::test(name, move || check(day, example)).with_ignored_flag(missing);
+            tests.push(test);
+        }
+        if day.generate(0, 1).is_some() {
+            let name = format!("y{}::day{:02}::generated", day.year(), day.number());
+            tests.push(Trial::test(name, move || check_generated(day)));
+            let name = format!("y{}::day{:02}::fuzz", day.year(), day.number());
+            tests.push(Trial::test(name, move || check_mutated(day)));
+        }
+    }
+
+    libtest_mimic::run(&args, tests).exit();
+}
+
+fn check(day: &Day, example: Example) -> Result<(), Failed> {
+    let input = input::file(day.year(), example.file)?;
+    let answers = day.solve(&input, &[example.part])?;
+    if answers[0] != example.expected {
+        return Err(format!("expected {}, got {}", example.expected, answers[0]).into());
+    }
+    Ok(())
+}
+
+fn check_generated(day: &Day) -> Result<(), Failed> {
+    for seed in 0..5 {
+        for size in [1, 10, 50] {
+            let text = day.generate(seed, size).unwrap();
+            let input = Input::new(format!("<seed {seed}, size {size}>"), text);
+            // Parse without solving any part.
+            day.run(&input, &[])?;
+        }
+    }
+    Ok(())
+}
+
+fn check_mutated(day: &Day) -> Result<(), Failed> {
+    let valid = day.generate(0, 10).unwrap();
+    for seed in 0..200 {
+        let text = random::mutate(&mut Rng::new(seed), &valid);
+        let input = Input::new(format!("<mutation {seed}>"), text);
+        // Parse errors are expected, only panics fail the test.
+        if panic::catch_unwind(AssertUnwindSafe(|| day.run(&input, &[]))).is_err() {
+            return Err(format!("parsing mutation {seed} panicked").into());
+        }
+    }
+    Ok(())
+}